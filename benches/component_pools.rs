@@ -0,0 +1,64 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ignition::life::Scene;
+
+macro_rules! component_types {
+    ($($name:ident),+) => {
+        $(
+            #[derive(Clone, Copy)]
+            struct $name(f32);
+        )+
+
+        fn populated_scene(entities: usize) -> Scene {
+            let mut scene = Scene::new();
+
+            for index in 0..entities {
+                let entity = scene.entity();
+                $(scene.component(entity, $name(index as f32));)+
+            }
+
+            scene
+        }
+
+        fn touch_every_pool(scene: &Scene, entity: usize) -> f32 {
+            0.0 $(+ scene.get_component::<$name>(entity).map_or(0.0, |component| component.0))+
+        }
+    };
+}
+
+component_types!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+
+fn pool_lookups(criterion: &mut Criterion) {
+    let scene = populated_scene(1_000);
+
+    criterion.bench_function("get 16 pools", |bencher| {
+        bencher.iter(|| {
+            black_box(scene.get::<A>().num_components)
+                + black_box(scene.get::<B>().num_components)
+                + black_box(scene.get::<C>().num_components)
+                + black_box(scene.get::<D>().num_components)
+                + black_box(scene.get::<E>().num_components)
+                + black_box(scene.get::<F>().num_components)
+                + black_box(scene.get::<G>().num_components)
+                + black_box(scene.get::<H>().num_components)
+                + black_box(scene.get::<I>().num_components)
+                + black_box(scene.get::<J>().num_components)
+                + black_box(scene.get::<K>().num_components)
+                + black_box(scene.get::<L>().num_components)
+                + black_box(scene.get::<M>().num_components)
+                + black_box(scene.get::<N>().num_components)
+                + black_box(scene.get::<O>().num_components)
+                + black_box(scene.get::<P>().num_components)
+        })
+    });
+
+    criterion.bench_function("get_component from 16 pools for 1000 entities", |bencher| {
+        bencher.iter(|| {
+            (0..1_000)
+                .map(|entity| touch_every_pool(&scene, black_box(entity)))
+                .sum::<f32>()
+        })
+    });
+}
+
+criterion_group!(benches, pool_lookups);
+criterion_main!(benches);
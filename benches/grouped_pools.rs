@@ -0,0 +1,61 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ignition::life::Scene;
+
+#[derive(Clone, Copy)]
+struct Position(f32);
+
+#[derive(Clone, Copy)]
+struct Velocity(f32);
+
+const ENTITIES: usize = 10_000;
+
+// Velocities are assigned in a scattered order so the two packed arrays disagree before grouping.
+fn interleaved_scene() -> Scene {
+    let mut scene = Scene::new();
+    let entities: Vec<usize> = (0..ENTITIES).map(|_| scene.entity().raw_id()).collect();
+
+    for &entity in &entities {
+        if entity % 4 != 3 {
+            scene.component(entity, Position(entity as f32));
+        }
+    }
+    for index in 0..ENTITIES {
+        let entity = entities[index * 7919 % ENTITIES];
+        if entity % 3 != 2 {
+            scene.component(entity, Velocity(entity as f32 * 0.5));
+        }
+    }
+
+    scene
+}
+
+fn joint_iteration(criterion: &mut Criterion) {
+    let mut scene = interleaved_scene();
+
+    criterion.bench_function("sparse join over 10000 entities", |bencher| {
+        bencher.iter(|| {
+            scene
+                .query::<(Position, Velocity)>()
+                .map(|(_, position, velocity)| position.0 + velocity.0)
+                .sum::<f32>()
+        })
+    });
+
+    scene.regroup::<Position, Velocity>();
+
+    criterion.bench_function("grouped iteration over 10000 entities", |bencher| {
+        bencher.iter(|| {
+            scene
+                .group::<Position, Velocity>()
+                .map(|(_, position, velocity)| position.0 + velocity.0)
+                .sum::<f32>()
+        })
+    });
+
+    criterion.bench_function("regroup 10000 entities", |bencher| {
+        bencher.iter(|| black_box(scene.regroup::<Position, Velocity>()))
+    });
+}
+
+criterion_group!(benches, joint_iteration);
+criterion_main!(benches);
@@ -0,0 +1,86 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ignition::life::Scene;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ENTITIES: usize = 10_000;
+
+#[derive(Clone, Copy)]
+struct Position(f32, f32);
+
+#[derive(Clone, Copy)]
+struct Velocity(f32, f32);
+
+fn populate(mut scene: Scene) -> Scene {
+    for index in 0..ENTITIES {
+        let entity = scene.entity();
+        scene.component(entity, Position(index as f32, 0.0));
+        scene.component(entity, Velocity(0.0, index as f32));
+    }
+
+    scene
+}
+
+fn checksum(scene: &Scene) -> f32 {
+    let positions: f32 = scene.iter::<Position>().map(|Position(x, y)| x + y).sum();
+    let velocities: f32 = scene.iter::<Velocity>().map(|Velocity(x, y)| x + y).sum();
+
+    positions + velocities
+}
+
+fn allocations(build: impl Fn() -> Scene) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let scene = build();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    black_box(checksum(&scene));
+
+    allocations
+}
+
+fn scene_capacity(criterion: &mut Criterion) {
+    // The timings only mean something if reserving up front actually saves allocations.
+    let grown = allocations(|| populate(Scene::new()));
+    let reserved = allocations(|| populate(Scene::with_capacity(ENTITIES)));
+    assert!(
+        reserved < grown,
+        "Error: Scene::with_capacity made {} allocations, Scene::new made {} - Ignition",
+        reserved,
+        grown
+    );
+
+    criterion.bench_function("spawn 10000 entities into Scene::new", |bencher| {
+        bencher.iter(|| populate(Scene::new()))
+    });
+
+    criterion.bench_function(
+        "spawn 10000 entities into Scene::with_capacity",
+        |bencher| bencher.iter(|| populate(Scene::with_capacity(ENTITIES))),
+    );
+}
+
+criterion_group!(benches, scene_capacity);
+criterion_main!(benches);
@@ -0,0 +1,95 @@
+use std::any::{type_name, TypeId};
+use std::collections::HashSet;
+
+use crate::Engine;
+
+pub trait Plugin: 'static {
+    fn build(&self, engine: &mut Engine);
+
+    fn name(&self) -> &'static str {
+        type_name::<Self>()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Plugins {
+    names: Vec<&'static str>,
+    type_ids: HashSet<TypeId>,
+}
+
+impl Plugins {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<P: Plugin>(&mut self, plugin: &P) -> bool {
+        if !self.type_ids.insert(TypeId::of::<P>()) {
+            return false;
+        }
+
+        self.names.push(plugin.name());
+        true
+    }
+
+    pub fn names(&self) -> &[&'static str] {
+        &self.names
+    }
+}
+
+impl Engine {
+    pub fn add_plugin<P: Plugin>(&mut self, plugin: P) -> bool {
+        if !self.plugins.register(&plugin) {
+            return false;
+        }
+
+        plugin.build(self);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        alliance::{Plugin, Plugins},
+        Engine,
+    };
+
+    struct CameraRig;
+
+    impl Plugin for CameraRig {
+        fn build(&self, _engine: &mut Engine) {}
+    }
+
+    struct DebugOverlay;
+
+    impl Plugin for DebugOverlay {
+        fn build(&self, _engine: &mut Engine) {}
+
+        fn name(&self) -> &'static str {
+            "debug_overlay"
+        }
+    }
+
+    #[test]
+    fn registering_plugins_records_their_names_in_order() {
+        let mut plugins = Plugins::new();
+
+        assert!(plugins.register(&CameraRig));
+        assert!(plugins.register(&DebugOverlay));
+
+        assert_eq!(
+            plugins.names(),
+            &["ignition::alliance::tests::CameraRig", "debug_overlay"]
+        );
+    }
+
+    #[test]
+    fn registering_the_same_plugin_type_twice_is_rejected() {
+        let mut plugins = Plugins::new();
+
+        plugins.register(&CameraRig);
+
+        assert!(!plugins.register(&CameraRig));
+        assert_eq!(plugins.names().len(), 1);
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::manifestation::scripture::ShaderDiagnostics;
+
+#[derive(Debug, PartialEq)]
+pub enum IgnitionError {
+    Shader(ShaderDiagnostics),
+    Image { path: PathBuf, message: String },
+    Io { path: PathBuf, message: String },
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for IgnitionError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IgnitionError::Shader(diagnostics) => diagnostics.fmt(formatter),
+            IgnitionError::Image { path, message } => write!(
+                formatter,
+                "Error: Unable to load image {} ({}) - Ignition",
+                path.display(),
+                message
+            ),
+            IgnitionError::Io { path, message } => write!(
+                formatter,
+                "Error: Unable to read {} ({}) - Ignition",
+                path.display(),
+                message
+            ),
+            IgnitionError::Unsupported(feature) => {
+                write!(formatter, "Error: Unsupported, {} - Ignition", feature)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IgnitionError {}
+
+impl From<ShaderDiagnostics> for IgnitionError {
+    fn from(diagnostics: ShaderDiagnostics) -> Self {
+        IgnitionError::Shader(diagnostics)
+    }
+}
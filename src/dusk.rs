@@ -0,0 +1,201 @@
+use std::mem;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use wgpu::Maintain;
+
+use crate::{
+    life::{caravan::DeferredSpawns, Scene},
+    manifestation::Renderer,
+    Engine,
+};
+
+pub type ExitCallback = Box<dyn FnOnce(&mut Scene)>;
+
+// How long shutdown waits for submitted GPU work before giving up on the driver.
+pub const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+
+impl Scene {
+    pub fn add_shutdown_system<F>(&mut self, system: F)
+    where
+        F: 'static + FnMut(&mut Scene),
+    {
+        self.shutdown_systems.push(Box::new(system));
+    }
+
+    // Pending deferred spawns are dropped, commands queued by the shutdown stage still apply.
+    pub fn shutdown(&mut self) {
+        self.remove_resource::<DeferredSpawns>();
+
+        let mut systems = mem::take(&mut self.shutdown_systems);
+        for system in systems.iter_mut() {
+            system(self);
+        }
+        self.shutdown_systems = systems;
+
+        self.apply_commands();
+        self.clear_events();
+    }
+}
+
+impl Renderer {
+    pub fn drain(&self, timeout: Duration) -> bool {
+        let done = Arc::new(AtomicBool::new(false));
+        let flag = done.clone();
+        self.queue
+            .on_submitted_work_done(move || flag.store(true, Ordering::Release));
+
+        wait_until(
+            || {
+                self.device.poll(Maintain::Poll);
+                done.load(Ordering::Acquire)
+            },
+            timeout,
+        )
+    }
+}
+
+impl Engine {
+    pub fn on_exit<F>(&mut self, callback: F)
+    where
+        F: 'static + FnOnce(&mut Scene),
+    {
+        self.exit_callbacks.push(Box::new(callback));
+    }
+
+    // Returns false when the GPU did not finish in time, the renderer should then be leaked rather than dropped.
+    pub fn shutdown(&mut self) -> bool {
+        let drained = self.renderer.drain(SHUTDOWN_TIMEOUT);
+        if !drained {
+            log::warn!(
+                "GPU work did not finish within {:?}, shutting down without it",
+                SHUTDOWN_TIMEOUT
+            );
+        }
+
+        for scene in self.scenes.iter_mut().rev() {
+            scene.shutdown();
+        }
+        wind_down(&mut self.scene, mem::take(&mut self.exit_callbacks));
+
+        drained
+    }
+}
+
+pub fn wind_down(scene: &mut Scene, callbacks: Vec<ExitCallback>) {
+    scene.shutdown();
+
+    for callback in callbacks {
+        callback(scene);
+    }
+}
+
+pub fn wait_until<F: FnMut() -> bool>(mut done: F, timeout: Duration) -> bool {
+    let start = Instant::now();
+
+    loop {
+        if done() {
+            return true;
+        }
+        if start.elapsed() >= timeout {
+            return false;
+        }
+
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        dusk::{wait_until, wind_down, ExitCallback},
+        life::{decree::spawn_command, Scene},
+    };
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Capture {
+        frames: Vec<u32>,
+        flushed: bool,
+    }
+
+    fn recording() -> Scene {
+        let mut scene = Scene::new();
+        scene.insert_resource(Capture::default());
+        scene.spawn_deferred(
+            (0..100).map(|value| spawn_command((value,))),
+            Duration::ZERO,
+        );
+
+        scene
+    }
+
+    #[test]
+    fn shutting_down_runs_the_shutdown_stage() {
+        let mut scene = recording();
+        scene.add_shutdown_system(|scene: &mut Scene| {
+            scene.resource_mut::<Capture>().frames.push(1);
+        });
+        scene.add_system(|scene: &mut Scene| {
+            scene.resource_mut::<Capture>().frames.push(0);
+        });
+
+        scene.shutdown();
+
+        assert_eq!(scene.resource::<Capture>().frames, vec![1]);
+    }
+
+    #[test]
+    fn shutting_down_drops_deferred_work_but_applies_commands() {
+        let mut scene = recording();
+        scene.add_shutdown_system(|scene: &mut Scene| {
+            scene.commands().spawn((1.5_f32,));
+        });
+
+        scene.shutdown();
+
+        assert_eq!(scene.pending_deferred_spawns(), 0);
+        assert_eq!(scene.component_count::<i32>(), 0);
+        assert_eq!(scene.component_count::<f32>(), 1);
+    }
+
+    #[test]
+    fn exit_callbacks_run_after_the_shutdown_stage() {
+        let mut scene = recording();
+        scene.add_shutdown_system(|scene: &mut Scene| {
+            scene.resource_mut::<Capture>().frames.push(1);
+        });
+        let flush: ExitCallback = Box::new(|scene: &mut Scene| {
+            let capture = scene.resource_mut::<Capture>();
+            capture.flushed = capture.frames == vec![1];
+        });
+
+        wind_down(&mut scene, vec![flush]);
+
+        assert!(scene.resource::<Capture>().flushed);
+    }
+
+    #[test]
+    fn waiting_stops_once_the_work_is_done() {
+        let mut polls = 0;
+
+        let done = wait_until(
+            || {
+                polls += 1;
+                polls == 3
+            },
+            Duration::from_secs(10),
+        );
+
+        assert!(done);
+        assert_eq!(polls, 3);
+    }
+
+    #[test]
+    fn waiting_on_a_poll_that_never_completes_times_out() {
+        assert!(!wait_until(|| false, Duration::from_millis(5)));
+    }
+}
@@ -0,0 +1,173 @@
+use std::time::{Duration, Instant};
+
+use crate::Engine;
+
+pub type Alarm = Box<dyn FnOnce(&mut Engine)>;
+
+// Longer frames are cut down to this, so one stall does not make the next update take even longer.
+pub const MAX_DELTA_TIME: Duration = Duration::from_millis(100);
+
+pub struct Alarms<T> {
+    now: Duration,
+    scheduled: u64,
+
+    pending: Vec<(Duration, u64, T)>,
+}
+
+impl<T> Alarms<T> {
+    pub fn new() -> Self {
+        Self {
+            now: Duration::ZERO,
+            scheduled: 0,
+
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn schedule(&mut self, duration: Duration, alarm: T) {
+        self.pending
+            .push((self.now + duration, self.scheduled, alarm));
+        self.scheduled += 1;
+    }
+
+    // Alarms due on the same frame come out in due order, then in the order they were scheduled.
+    pub fn advance(&mut self, delta: Duration) -> Vec<T> {
+        self.now += delta;
+
+        let (mut due, pending) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(due_at, _, _)| *due_at <= self.now);
+        self.pending = pending;
+
+        due.sort_by_key(|(due_at, scheduled, _)| (*due_at, *scheduled));
+        due.into_iter().map(|(_, _, alarm)| alarm).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+impl<T> Default for Alarms<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Engine {
+    pub fn after<F>(&mut self, duration: Duration, callback: F)
+    where
+        F: FnOnce(&mut Engine) + 'static,
+    {
+        self.alarms.schedule(duration, Box::new(callback));
+    }
+
+    // Seconds covered by the current frame, the same delta the scene systems and alarms advance by.
+    pub fn delta_time(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    pub fn advance_frame(&mut self, now: Instant) -> Duration {
+        self.delta = frame_delta(self.last_frame_instant, now);
+        self.last_frame_instant = now;
+
+        self.delta
+    }
+
+    pub fn run_alarms(&mut self, delta: Duration) {
+        for alarm in self.alarms.advance(delta) {
+            alarm(self);
+        }
+    }
+}
+
+pub fn frame_delta(last_frame: Instant, now: Instant) -> Duration {
+    now.saturating_duration_since(last_frame)
+        .min(MAX_DELTA_TIME)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::hourglass::{frame_delta, Alarms, MAX_DELTA_TIME};
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn alarms_fire_once_they_are_due() {
+        let mut alarms = Alarms::new();
+
+        alarms.schedule(millis(30), "ring");
+
+        assert_eq!(alarms.advance(millis(20)), Vec::<&str>::new());
+        assert_eq!(alarms.advance(millis(10)), vec!["ring"]);
+        assert_eq!(alarms.advance(millis(100)), Vec::<&str>::new());
+        assert!(alarms.is_empty());
+    }
+
+    #[test]
+    fn alarms_due_on_the_same_frame_fire_in_due_order() {
+        let mut alarms = Alarms::new();
+
+        alarms.schedule(millis(30), "third");
+        alarms.schedule(millis(10), "first");
+        alarms.schedule(millis(20), "second");
+
+        assert_eq!(alarms.advance(millis(50)), vec!["first", "second", "third"]);
+    }
+
+    #[test]
+    fn alarms_due_at_the_same_time_fire_in_scheduling_order() {
+        let mut alarms = Alarms::new();
+
+        alarms.schedule(millis(10), "first");
+        alarms.schedule(millis(10), "second");
+        alarms.schedule(millis(10), "third");
+
+        assert_eq!(alarms.advance(millis(10)), vec!["first", "second", "third"]);
+    }
+
+    #[test]
+    fn alarms_are_relative_to_when_they_were_scheduled() {
+        let mut alarms = Alarms::new();
+
+        alarms.advance(millis(100));
+        alarms.schedule(millis(10), "late");
+
+        assert_eq!(alarms.advance(millis(5)), Vec::<&str>::new());
+        assert_eq!(alarms.len(), 1);
+        assert_eq!(alarms.advance(millis(5)), vec!["late"]);
+    }
+
+    #[test]
+    fn frame_delta_is_the_time_since_the_last_frame() {
+        let last_frame = Instant::now();
+
+        assert_eq!(frame_delta(last_frame, last_frame + millis(16)), millis(16));
+    }
+
+    #[test]
+    fn long_frames_are_clamped() {
+        let last_frame = Instant::now();
+
+        assert_eq!(
+            frame_delta(last_frame, last_frame + millis(2500)),
+            MAX_DELTA_TIME
+        );
+        assert_eq!(MAX_DELTA_TIME.as_secs_f32(), 0.1);
+    }
+
+    #[test]
+    fn frames_never_go_backwards() {
+        let now = Instant::now();
+
+        assert_eq!(frame_delta(now + millis(5), now), Duration::ZERO);
+    }
+}
@@ -1,30 +1,54 @@
 #[macro_use]
 extern crate derive_builder;
 
+pub mod alliance;
+pub mod calamity;
+pub mod dusk;
+pub mod hourglass;
 pub mod liberty;
 pub mod life;
 pub mod manifestation;
 pub mod prelude;
+pub mod senses;
+pub mod theatre;
 
-use crate::liberty::{Parameters, ParametersBuilder};
-use crate::life::Scene;
+use std::time::{Duration, Instant};
+
+use crate::alliance::Plugins;
+use crate::dusk::ExitCallback;
+use crate::hourglass::{Alarm, Alarms};
+use crate::liberty::{EngineOptions, Parameters};
+use crate::life::{Entity, Scene};
 use crate::manifestation::Renderer;
+use crate::senses::InputState;
 
 pub struct Engine {
     pub renderer: Renderer,
     pub scene: Scene,
+    pub scenes: Vec<Scene>,
+    pub building: Option<Entity>,
+    pub input: InputState,
+    pub plugins: Plugins,
+    pub alarms: Alarms<Alarm>,
+    pub exit_callbacks: Vec<ExitCallback>,
+    pub last_frame_instant: Instant,
+    pub delta: Duration,
 
     pub parameters: Parameters,
 }
 
 impl Engine {
-    pub fn ignite() -> Self {
+    pub fn ignite(options: EngineOptions) -> Self {
         Self::env_logger();
-        Engine::setup_engine(ParametersBuilder::default().build().unwrap())
+        Engine::setup_engine(options.build().unwrap_or_else(|error| panic!("{}", error)))
+    }
+
+    pub fn ignite_default() -> Self {
+        Engine::ignite(EngineOptions::default())
     }
 
-    pub fn parameters() -> ParametersBuilder {
-        ParametersBuilder::default()
+    pub fn parameters() -> EngineOptions {
+        EngineOptions::default()
     }
 
     pub fn env_logger() {
@@ -37,6 +61,14 @@ impl Engine {
         Self {
             renderer: Renderer::new(&parameters),
             scene: Scene::new(),
+            scenes: Vec::new(),
+            building: None,
+            input: InputState::new(),
+            plugins: Plugins::new(),
+            alarms: Alarms::new(),
+            exit_callbacks: Vec::new(),
+            last_frame_instant: Instant::now(),
+            delta: Duration::ZERO,
 
             parameters,
         }
@@ -2,12 +2,20 @@ use winit::event_loop::ControlFlow;
 
 use crate::Engine;
 
+pub const SAMPLE_COUNTS: &[u32] = &[1, 2, 4];
+
 #[derive(Builder, Debug, PartialEq)]
-#[builder(default)]
+#[builder(name = "EngineOptions", default, build_fn(validate = "Self::validate"))]
 pub struct Parameters {
     pub control_flow: ControlFlow,
 
+    #[builder(setter(name = "title", into))]
     pub window_title: String,
+    pub width: u32,
+    pub height: u32,
+
+    pub vsync: bool,
+    pub sample_count: u32,
 }
 
 impl Default for Parameters {
@@ -16,26 +24,52 @@ impl Default for Parameters {
             control_flow: ControlFlow::Poll,
 
             window_title: String::from("The Dark Web"),
+            width: 800,
+            height: 600,
+
+            vsync: true,
+            sample_count: 1,
         }
     }
 }
 
-impl ParametersBuilder {
+impl EngineOptions {
     pub fn ignite(&mut self) -> Engine {
-        Engine::env_logger();
-        Engine::setup_engine(self.build().unwrap())
+        Engine::ignite(self.clone())
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if let Some(sample_count) = self.sample_count {
+            if !SAMPLE_COUNTS.contains(&sample_count) {
+                return Err(format!(
+                    "Error: Sample count {} is not supported, use one of {:?} - Ignition",
+                    sample_count, SAMPLE_COUNTS
+                ));
+            }
+        }
+
+        if self.width == Some(0) || self.height == Some(0) {
+            return Err(String::from(
+                "Error: Window width and height must be greater than zero - Ignition",
+            ));
+        }
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{liberty::ParametersBuilder, Engine};
+    use crate::{
+        liberty::{EngineOptions, Parameters},
+        Engine,
+    };
     use winit::event_loop::ControlFlow;
 
     #[test]
     fn instantiating_parameters_with_engine_returns_correct_defaults() {
-        let engine = Engine::ignite();
-        let default_parameters = ParametersBuilder::default().build().unwrap();
+        let engine = Engine::ignite_default();
+        let default_parameters = EngineOptions::default().build().unwrap();
 
         assert_eq!(engine.parameters, default_parameters);
     }
@@ -46,11 +80,72 @@ mod tests {
             .control_flow(ControlFlow::Wait)
             .ignite();
 
-        let default_parameters = ParametersBuilder::default()
+        let default_parameters = EngineOptions::default()
             .control_flow(ControlFlow::Wait)
             .build()
             .unwrap();
 
         assert_eq!(engine.parameters, default_parameters);
     }
+
+    #[test]
+    fn empty_options_build_the_default_parameters() {
+        assert_eq!(
+            EngineOptions::default().build().unwrap(),
+            Parameters::default()
+        );
+    }
+
+    #[test]
+    fn options_override_only_what_they_set() {
+        let parameters = EngineOptions::default()
+            .title("Ignition")
+            .width(1280)
+            .height(720)
+            .vsync(false)
+            .sample_count(4)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            parameters,
+            Parameters {
+                window_title: String::from("Ignition"),
+                width: 1280,
+                height: 720,
+                vsync: false,
+                sample_count: 4,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn supported_sample_counts_are_accepted() {
+        for sample_count in [1, 2, 4] {
+            let parameters = EngineOptions::default()
+                .sample_count(sample_count)
+                .build()
+                .unwrap();
+
+            assert_eq!(parameters.sample_count, sample_count);
+        }
+    }
+
+    #[test]
+    fn unsupported_sample_counts_are_rejected() {
+        let error = EngineOptions::default()
+            .sample_count(3)
+            .build()
+            .unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("Sample count 3 is not supported"));
+    }
+
+    #[test]
+    fn zero_sized_windows_are_rejected() {
+        assert!(EngineOptions::default().width(0).build().is_err());
+    }
 }
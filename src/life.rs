@@ -1,29 +1,92 @@
 use std::any::TypeId;
+use std::cell::RefCell;
 use std::collections::HashMap;
 
 use annihilation::EntityDestructor;
+use catalogue::PoolRegistry;
+use chimera::DynamicInserter;
+use decree::Command;
+use echo::Cloner;
+use evolution::System;
+use fossil::PoolSerializer;
 use genesis::entity::EntityConstructor;
 use ghost::ComponentToggler;
 use gizmos::PoolToolbox;
+use herald::EventQueue;
+use insignia::TagTable;
+use ledger::PoolStats;
+use sentinel::ComponentHooks;
+use treasury::Resource;
 
 pub mod abduction;
 pub mod annihilation;
+pub mod caravan;
+pub mod catalogue;
+pub mod census;
+pub mod chimera;
+pub mod chronicle;
+pub mod clockwork;
+pub mod cohort;
+pub mod custody;
+pub mod decree;
+pub mod echo;
+pub mod evolution;
+pub mod footprints;
+pub mod fossil;
 pub mod genesis;
 pub mod ghost;
 pub mod gizmos;
+pub mod herald;
+pub mod insignia;
+pub mod ledger;
+pub mod lineage;
+pub mod mitosis;
+pub mod procession;
+pub mod sentinel;
+#[cfg(feature = "parallel")]
+pub mod swarm;
+pub mod treasury;
 
 pub struct Scene {
-    pub available_entities: Vec<usize>,
-    pub component_pools: HashMap<TypeId, Box<dyn ComponentPoolTrait>>,
+    pub next_id: usize,
+    pub free_list: Vec<usize>,
+    pub alive: Vec<bool>,
+    pub generations: Vec<u32>,
+    pub component_pools: PoolRegistry,
+    pub resources: HashMap<TypeId, Resource>,
+    pub events: HashMap<TypeId, EventQueue>,
+    pub tags: TagTable,
+    pub systems: Vec<System>,
+    pub shutdown_systems: Vec<System>,
+    pub commands: RefCell<Vec<Command>>,
+    pub serializers: HashMap<TypeId, PoolSerializer>,
+    pub cloners: HashMap<TypeId, Cloner>,
+    pub inserters: HashMap<TypeId, DynamicInserter>,
+    pub hooks: HashMap<TypeId, ComponentHooks>,
+    pub drained: HashMap<TypeId, Vec<bool>>,
+
+    pub render_below: bool,
+    pub update_below: bool,
+
+    pub capacity: usize,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct Entity {
+    id: usize,
+    generation: u32,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct ComponentPool<G> {
     pub num_components: usize,
 
-    pub sparse_array: Vec<i32>,
+    pub sparse_array: Vec<Option<u32>>,
     pub packed_array: Vec<usize>,
+    // Vec never allocates for zero-sized types, so marker components only pay for the sparse and packed arrays.
     pub component_array: Vec<G>,
+
+    pub changed: Vec<bool>,
 }
 
 pub trait ComponentPoolTrait:
@@ -31,4 +94,8 @@ pub trait ComponentPoolTrait:
 {
     fn as_any(&self) -> &dyn std::any::Any;
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
+    fn contains_entity(&self, entity: usize) -> bool;
+    fn type_name(&self) -> &'static str;
+    fn entities(&self) -> &[usize];
+    fn stats(&self) -> PoolStats;
 }
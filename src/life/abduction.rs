@@ -1,55 +1,125 @@
-use crate::life::{ComponentPool, ComponentPoolTrait, Scene};
-use std::any::TypeId;
+use crate::life::{
+    genesis::entity::EntityKey, ledger::PoolStats, ComponentPool, ComponentPoolTrait, Scene,
+};
+use std::any::{type_name, TypeId};
 
 impl Scene {
-    pub fn get_trait<G: 'static>(&self) -> &Box<dyn ComponentPoolTrait> {
-        self.component_pools.get(&TypeId::of::<G>()).unwrap()
+    pub fn get_trait<G: 'static>(&self) -> &dyn ComponentPoolTrait {
+        self.component_pools
+            .get(&TypeId::of::<G>())
+            .unwrap_or_else(|| missing_component_pool::<G>())
     }
 
     pub fn get_trait_mut<G: 'static>(&mut self) -> &mut Box<dyn ComponentPoolTrait> {
-        self.component_pools.get_mut(&TypeId::of::<G>()).unwrap()
+        self.component_pools
+            .get_mut(&TypeId::of::<G>())
+            .unwrap_or_else(|| missing_component_pool::<G>())
     }
 
     pub fn get<G: 'static>(&self) -> &ComponentPool<G> {
-        self.get_trait::<G>()
+        self.try_get::<G>()
+            .unwrap_or_else(|| missing_component_pool::<G>())
+    }
+
+    pub fn try_get<G: 'static>(&self) -> Option<&ComponentPool<G>> {
+        self.component_pools
+            .get(&TypeId::of::<G>())?
             .as_any()
             .downcast_ref::<ComponentPool<G>>()
-            .unwrap()
     }
 
     pub fn get_mut<G: 'static>(&mut self) -> &mut ComponentPool<G> {
-        self.get_trait_mut::<G>()
+        self.try_get_mut::<G>()
+            .unwrap_or_else(|| missing_component_pool::<G>())
+    }
+
+    pub fn try_get_mut<G: 'static>(&mut self) -> Option<&mut ComponentPool<G>> {
+        self.component_pools
+            .get_mut(&TypeId::of::<G>())?
             .as_any_mut()
             .downcast_mut::<ComponentPool<G>>()
-            .unwrap()
     }
 
-    pub fn get_component<G: 'static>(&self, entity: usize) -> &G {
-        self.get::<G>().get(entity)
+    // Panics when A and B are the same type, since that would alias one pool.
+    pub fn get_two_mut<A: 'static, B: 'static>(
+        &mut self,
+    ) -> (&mut ComponentPool<A>, &mut ComponentPool<B>) {
+        let [a, b] = self
+            .component_pools
+            .get_disjoint_mut([&TypeId::of::<A>(), &TypeId::of::<B>()]);
+
+        (
+            a.and_then(|pool| pool.as_any_mut().downcast_mut::<ComponentPool<A>>())
+                .unwrap_or_else(|| missing_component_pool::<A>()),
+            b.and_then(|pool| pool.as_any_mut().downcast_mut::<ComponentPool<B>>())
+                .unwrap_or_else(|| missing_component_pool::<B>()),
+        )
+    }
+
+    pub fn get_component<G: 'static>(&self, entity: impl EntityKey) -> Option<&G> {
+        self.try_get::<G>()?.try_get(entity.resolve_in(self)?)
     }
 
-    pub fn get_component_mut<G: 'static>(&mut self, entity: usize) -> &mut G {
-        self.get_mut::<G>().get_mut(entity)
+    // The returned borrow holds the whole Scene mutably, so no other pool can be
+    // read until it is dropped; use query_mut to work on several pools at once.
+    pub fn get_component_mut<G: 'static>(&mut self, entity: impl EntityKey) -> Option<&mut G> {
+        let entity = entity.resolve_in(self)?;
+
+        self.try_get_mut::<G>()?.try_get_mut(entity)
     }
 
-    pub fn take_component<G: 'static>(&mut self, entity: usize) -> G {
-        self.get_mut::<G>().take_entity(entity).unwrap()
+    pub fn take_component<G: 'static>(&mut self, entity: impl EntityKey) -> G {
+        let entity = entity
+            .resolve_in(self)
+            .unwrap_or_else(|| stale_entity(entity));
+        self.run_remove_hooks(TypeId::of::<G>(), entity);
+        self.get_mut::<G>().remove(entity).unwrap()
     }
 
     pub fn get_current_entity(&self) -> usize {
-        self.available_entities[self.available_entities.len() - 1]
+        self.free_list.last().copied().unwrap_or(self.next_id)
     }
 }
 
+pub fn missing_component_pool<G>() -> ! {
+    panic!(
+        "Error: No component pool exists for {} - Ignition",
+        type_name::<G>()
+    )
+}
+
+pub fn stale_entity(entity: impl EntityKey) -> ! {
+    panic!("Error: {:?} was deleted - Ignition", entity)
+}
+
+fn missing_component<G>(entity: usize) -> ! {
+    panic!(
+        "Error: Entity {} has no {} component - Ignition",
+        entity,
+        type_name::<G>()
+    )
+}
+
 impl<G> ComponentPool<G> {
     pub fn get(&self, entity: usize) -> &G {
-        let index = self.sparse_array[entity] as usize;
-        self.component_array.get(index).unwrap()
+        self.try_get(entity)
+            .unwrap_or_else(|| missing_component::<G>(entity))
+    }
+
+    pub fn try_get(&self, entity: usize) -> Option<&G> {
+        self.component_array.get(self.index_of(entity)?)
     }
 
     pub fn get_mut(&mut self, entity: usize) -> &mut G {
-        let index = self.sparse_array[entity] as usize;
-        self.component_array.get_mut(index).unwrap()
+        self.try_get_mut(entity)
+            .unwrap_or_else(|| missing_component::<G>(entity))
+    }
+
+    pub fn try_get_mut(&mut self, entity: usize) -> Option<&mut G> {
+        let index = self.index_of(entity)?;
+        self.mark_changed(entity);
+
+        self.component_array.get_mut(index)
     }
 }
 
@@ -61,12 +131,53 @@ impl<G: 'static> ComponentPoolTrait for ComponentPool<G> {
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self as &mut dyn std::any::Any
     }
+
+    fn contains_entity(&self, entity: usize) -> bool {
+        self.has_component(entity)
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<G>()
+    }
+
+    fn entities(&self) -> &[usize] {
+        &self.packed_array
+    }
+
+    fn stats(&self) -> PoolStats {
+        ComponentPool::stats(self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::life::Scene;
 
+    #[test]
+    fn two_pools_can_be_borrowed_mutably_at_once() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+        scene.component(entity, 5_i32);
+        scene.component(entity, 0.5_f32);
+
+        let (integers, floats) = scene.get_two_mut::<i32, f32>();
+        *floats.get_mut(entity.raw_id()) += *integers.get(entity.raw_id()) as f32;
+        *integers.get_mut(entity.raw_id()) = 7;
+
+        assert_eq!(scene.get_component::<i32>(entity), Some(&7));
+        assert_eq!(scene.get_component::<f32>(entity), Some(&5.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "requested mutably more than once")]
+    fn borrowing_the_same_pool_twice_panics() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+        scene.component(entity, 5_i32);
+
+        scene.get_two_mut::<i32, i32>();
+    }
+
     #[test]
     fn calling_get_current_entity_returns_correct_id() {
         let scene = Scene::new();
@@ -89,8 +200,142 @@ mod tests {
     #[test]
     fn requesting_for_non_existing_component_pool_returns_error() {
         let mut scene = Scene::new();
-        scene.component(scene.entity(), 1 as i32);
+        let entity = scene.entity();
+        scene.component(entity, 1_i32);
+
+        assert_eq!(scene.try_get::<f32>(), None);
+    }
+
+    #[test]
+    fn requesting_for_non_existing_component_pool_mutably_returns_none() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+        scene.component(entity, 1_i32);
+
+        assert_eq!(scene.try_get_mut::<f32>(), None);
+        assert_eq!(scene.try_get_mut::<i32>().unwrap().num_components, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: No component pool exists for f32 - Ignition")]
+    fn getting_non_existing_component_pool_panics_with_type_name() {
+        let scene = Scene::new();
+
+        scene.get::<f32>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: No component pool exists for alloc::vec::Vec<u8> - Ignition")]
+    fn getting_non_existing_component_pool_mutably_panics_with_type_name() {
+        let mut scene = Scene::new();
+
+        scene.get_mut::<Vec<u8>>();
+    }
+
+    #[test]
+    fn getting_a_single_component_returns_it() {
+        let mut scene = Scene::new();
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.component(entity1, 32_i32);
+        scene.component(entity2, 21_i32);
+
+        assert_eq!(scene.get_component::<i32>(entity2), Some(&21));
+    }
+
+    #[test]
+    fn getting_a_missing_single_component_returns_none() {
+        let mut scene = Scene::new();
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.component(entity1, 32_i32);
+
+        assert_eq!(scene.get_component::<i32>(entity2), None);
+        assert_eq!(scene.get_component::<i32>(100), None);
+        assert_eq!(scene.get_component::<f32>(entity1), None);
+    }
+
+    #[test]
+    fn getting_a_single_component_mutably_allows_updating_it() {
+        let mut scene = Scene::new();
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.component(entity1, 32_i32);
+        scene.component(entity2, 21_i32);
+
+        *scene.get_component_mut::<i32>(entity2).unwrap() += 1;
+
+        assert_eq!(
+            scene.get::<i32>().iter().collect::<Vec<_>>(),
+            vec![&32, &22]
+        );
+    }
+
+    #[test]
+    fn getting_a_missing_single_component_mutably_returns_none() {
+        let mut scene = Scene::new();
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.component(entity1, 32_i32);
+
+        assert_eq!(scene.get_component_mut::<i32>(entity2), None);
+        assert_eq!(scene.get_component_mut::<i32>(100), None);
+        assert_eq!(scene.get_component_mut::<f32>(entity1), None);
+    }
+
+    #[test]
+    fn getting_a_component_beyond_the_sparse_array_returns_none() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+        scene.component(entity, 32_i32);
+
+        assert_eq!(scene.get::<i32>().sparse_array.len(), 1);
+        assert_eq!(scene.get_component::<i32>(5), None);
+        assert_eq!(scene.get_component_mut::<i32>(5), None);
+    }
+
+    #[test]
+    fn getting_a_component_from_an_empty_slot_returns_none() {
+        let mut scene = Scene::new();
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.component(entity2, 21_i32);
+
+        assert_eq!(scene.get::<i32>().sparse_array[entity1.raw_id()], None);
+        assert_eq!(scene.get_component::<i32>(entity1), None);
+        assert_eq!(scene.get_component_mut::<i32>(entity1), None);
+        assert_eq!(scene.changed::<i32>(), vec![entity2.raw_id()]);
+    }
+
+    #[test]
+    fn getting_an_existing_component_returns_it() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+        scene.component(entity, 32_i32);
+
+        assert_eq!(scene.get_component::<i32>(entity), Some(&32));
+        *scene.get_component_mut::<i32>(entity).unwrap() = 33;
+        assert_eq!(scene.get_component::<i32>(entity), Some(&33));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Entity 4 has no i32 component - Ignition")]
+    fn getting_a_missing_component_from_a_pool_panics_with_entity_and_type_name() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+        scene.component(entity, 32_i32);
+
+        scene.get::<i32>().get(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Entity 0 has no u8 component - Ignition")]
+    fn getting_a_missing_component_mutably_from_a_pool_panics_with_entity_and_type_name() {
+        let mut scene = Scene::new();
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.component(entity2, 8_u8);
 
-        assert_eq!(scene.get_trait::<f32>(), LifeError::NoComponentPool);
+        scene.get_mut::<u8>().get_mut(entity1.raw_id());
     }
 }
@@ -1,36 +1,143 @@
-use crate::life::{ComponentPool, Scene};
+use std::any::TypeId;
+use std::mem;
+
+use crate::life::{genesis::entity::EntityKey, gizmos::PoolToolbox, ComponentPool, Scene};
 
 impl Scene {
-    pub fn delete(&mut self, entity: usize) {
-        self.available_entities.push(entity);
+    // Deleting a stale handle or an id that is not live, including one that was already deleted, does nothing.
+    pub fn delete(&mut self, entity: impl EntityKey) -> bool {
+        let entity = match entity.resolve_in(self) {
+            Some(entity) if self.is_live(entity) => entity,
+            _ => return false,
+        };
+
+        self.detach(entity);
+        self.free_list.push(entity);
+        self.alive[entity] = false;
+        self.bump_generation(entity);
         self.delete_entity_from_each_component_pool(entity);
+        self.tags.delete_entity(entity);
+
+        true
+    }
+
+    pub fn bump_generation(&mut self, entity: usize) {
+        if entity >= self.generations.len() {
+            self.generations.resize(entity + 1, 0);
+        }
+
+        self.generations[entity] = self.generations[entity].wrapping_add(1);
+    }
+
+    pub fn remove_component<G: 'static>(&mut self, entity: impl EntityKey) -> Option<G> {
+        let entity = entity.resolve_in(self)?;
+
+        if self.component_pool_exists::<G>() {
+            self.run_remove_hooks(TypeId::of::<G>(), entity);
+            self.get_mut::<G>().remove(entity)
+        } else {
+            None
+        }
+    }
+
+    // Remove hooks run for every component that goes away, the hooks themselves are kept. Every id handed
+    // out so far has its generation bumped, so handles taken before the clear stay dead once ids are reused.
+    pub fn clear(&mut self) {
+        let type_ids: Vec<TypeId> = self.hooks.keys().copied().collect();
+        for type_id in type_ids {
+            self.run_remove_hooks_for_every_entity(type_id);
+        }
+
+        for entity in 0..self.next_id {
+            self.bump_generation(entity);
+        }
+
+        let mut scene = Scene::new();
+
+        scene.generations = mem::take(&mut self.generations);
+        scene.systems = mem::take(&mut self.systems);
+        scene.shutdown_systems = mem::take(&mut self.shutdown_systems);
+        scene.serializers = mem::take(&mut self.serializers);
+        scene.cloners = mem::take(&mut self.cloners);
+        scene.inserters = mem::take(&mut self.inserters);
+        scene.hooks = mem::take(&mut self.hooks);
+        scene.render_below = self.render_below;
+        scene.update_below = self.update_below;
+        scene.capacity = self.capacity;
+
+        *self = scene;
+    }
+
+    pub fn clear_components<G: 'static>(&mut self) {
+        self.run_remove_hooks_for_every_entity(TypeId::of::<G>());
+        if let Some(component_pool) = self.try_get_mut::<G>() {
+            component_pool.clear();
+        }
     }
 
     pub fn delete_entity_from_each_component_pool(&mut self, entity: usize) {
+        if !self.hooks.is_empty() {
+            let type_ids: Vec<TypeId> = self.hooks.keys().copied().collect();
+            for type_id in type_ids {
+                self.run_remove_hooks(type_id, entity);
+            }
+        }
+
         for (_type_id, component_pool) in self.component_pools.iter_mut() {
             component_pool.delete_entity(entity);
         }
     }
 }
 
-impl<G> ComponentPool<G> {
-    pub fn take_entity(&mut self, entity: usize) -> Option<G> {
-        let index = self.sparse_array[entity];
+impl<G: 'static> ComponentPool<G> {
+    pub fn remove(&mut self, entity: usize) -> Option<G> {
+        if !self.has_component(entity) {
+            return None;
+        }
 
-        if index != -1 {
+        let mut index = self.index_of(entity).unwrap();
+
+        if index < self.num_components {
+            let last_enabled_index = self.num_components - 1;
+            self.swap_components(index, last_enabled_index);
+
+            index = last_enabled_index;
             self.num_components -= 1;
+        }
 
-            let last_index = self.sparse_array.len() - 1;
-            self.sparse_array[last_index] = self.sparse_array[entity];
-            self.sparse_array[entity] = -1;
+        let last_index = self.packed_array.len() - 1;
+        self.swap_components(index, last_index);
 
-            self.packed_array[index as usize] = last_index;
-            self.packed_array.remove(index as usize);
+        self.sparse_array[entity] = None;
+        self.unmark_changed(entity);
+        self.packed_array.pop();
+        self.component_array.pop()
+    }
 
-            Some(self.component_array.swap_remove(index as usize))
-        } else {
-            None
+    // Walks the packed array backwards, so every component swapped into a freed slot has already been
+    // kept. Disabled components are checked too. Ids come back in the order they were removed.
+    pub fn retain(&mut self, keep: impl Fn(&G) -> bool) -> Vec<usize> {
+        let mut removed = Vec::new();
+
+        for index in (0..self.packed_array.len()).rev() {
+            if !keep(&self.component_array[index]) {
+                let entity = self.packed_array[index];
+                self.remove(entity);
+                removed.push(entity);
+            }
         }
+
+        removed
+    }
+
+    pub fn clear(&mut self) {
+        self.num_components = 0;
+
+        self.sparse_array.clear();
+        self.packed_array.clear();
+        self.component_array.clear();
+
+        self.changed.clear();
     }
 }
 
@@ -40,18 +147,20 @@ pub trait EntityDestructor {
 
 impl<G: 'static> EntityDestructor for ComponentPool<G> {
     fn delete_entity(&mut self, entity: usize) {
-        self.take_entity(entity);
+        self.remove(entity);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::life::{annihilation::EntityDestructor, ComponentPool};
+    use crate::life::{
+        annihilation::EntityDestructor, ghost::ComponentToggler, ComponentPool, Scene,
+    };
 
     #[test]
     fn deleting_an_entity_updates_component_pool_correctly() {
-        let mut pool = ComponentPool::new_with_entity(1, 32 as i32);
-        pool.assign_component(2, 21 as i32);
+        let mut pool = ComponentPool::new_with_entity(1, 32_i32);
+        pool.assign_component(2, 21_i32);
 
         pool.delete_entity(1);
 
@@ -60,16 +169,18 @@ mod tests {
             ComponentPool {
                 num_components: 1,
 
-                sparse_array: vec![-1, -1, 0],
+                sparse_array: vec![None, None, Some(0)],
                 packed_array: vec![2],
                 component_array: vec![21],
+
+                changed: vec![false, false, true],
             },
         );
     }
 
     #[test]
     fn deleting_last_entity_doesn_t_swap_with_non_existing_component() {
-        let mut pool = ComponentPool::new_with_entity(1, 32 as i32);
+        let mut pool = ComponentPool::new_with_entity(1, 32_i32);
 
         pool.delete_entity(1);
 
@@ -78,16 +189,18 @@ mod tests {
             ComponentPool {
                 num_components: 0,
 
-                sparse_array: vec![-1, -1],
+                sparse_array: vec![None, None],
                 packed_array: vec![],
                 component_array: vec![],
+
+                changed: vec![false, false],
             },
         );
     }
 
     #[test]
     fn deleting_a_non_existing_entity_does_nothing() {
-        let mut pool = ComponentPool::new_with_entity(1, 32 as i32);
+        let mut pool = ComponentPool::new_with_entity(1, 32_i32);
         pool.delete_entity(0);
 
         assert_eq!(
@@ -95,10 +208,365 @@ mod tests {
             ComponentPool {
                 num_components: 1,
 
-                sparse_array: vec![-1, 0],
+                sparse_array: vec![None, Some(0)],
+                packed_array: vec![1],
+                component_array: vec![32],
+
+                changed: vec![false, true],
+            },
+        );
+    }
+
+    #[test]
+    fn removing_a_component_swaps_last_component_into_its_place() {
+        let mut pool = ComponentPool::new_with_entity(1, 32_i32);
+        pool.assign_component(2, 21_i32);
+        pool.assign_component(4, 17_i32);
+
+        assert_eq!(pool.remove(1), Some(32));
+
+        assert_eq!(
+            pool,
+            ComponentPool {
+                num_components: 2,
+
+                sparse_array: vec![None, None, Some(1), None, Some(0)],
+                packed_array: vec![4, 2],
+                component_array: vec![17, 21],
+
+                changed: vec![false, false, true, false, true],
+            },
+        );
+    }
+
+    #[test]
+    fn removing_the_last_component_does_not_move_other_components() {
+        let mut pool = ComponentPool::new_with_entity(1, 32_i32);
+        pool.assign_component(2, 21_i32);
+
+        assert_eq!(pool.remove(2), Some(21));
+
+        assert_eq!(
+            pool,
+            ComponentPool {
+                num_components: 1,
+
+                sparse_array: vec![None, Some(0), None],
                 packed_array: vec![1],
                 component_array: vec![32],
+
+                changed: vec![false, true, false],
+            },
+        );
+    }
+
+    #[test]
+    fn removing_the_only_component_empties_the_pool() {
+        let mut pool = ComponentPool::new_with_entity(1, 32_i32);
+
+        assert_eq!(pool.remove(1), Some(32));
+
+        assert_eq!(
+            pool,
+            ComponentPool {
+                num_components: 0,
+
+                sparse_array: vec![None, None],
+                packed_array: vec![],
+                component_array: vec![],
+
+                changed: vec![false, false],
+            },
+        );
+    }
+
+    #[test]
+    fn removing_a_non_existing_component_returns_none() {
+        let mut pool = ComponentPool::new_with_entity(1, 32_i32);
+
+        assert_eq!(pool.remove(0), None);
+        assert_eq!(pool.remove(7), None);
+
+        assert_eq!(
+            pool,
+            ComponentPool {
+                num_components: 1,
+
+                sparse_array: vec![None, Some(0)],
+                packed_array: vec![1],
+                component_array: vec![32],
+
+                changed: vec![false, true],
+            },
+        );
+    }
+
+    #[test]
+    fn removing_a_component_keeps_disabled_components_at_the_back() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, 32_i32);
+        let entity2 = scene.entity();
+        scene.component(entity2, 21_i32);
+        let entity3 = scene.entity();
+        scene.component(entity3, 17_i32);
+
+        scene.disable::<i32>(entity3);
+
+        assert_eq!(scene.remove_component::<i32>(entity1), Some(32));
+
+        assert_eq!(
+            scene.get::<i32>(),
+            &ComponentPool {
+                num_components: 1,
+
+                sparse_array: vec![None, Some(0), Some(1)],
+                packed_array: vec![1, 2],
+                component_array: vec![21, 17],
+
+                changed: vec![false, true, true],
+            },
+        );
+    }
+
+    #[test]
+    fn removing_a_component_from_a_scene_leaves_other_pools_untouched() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.component(entity, 32_i32);
+        scene.component(entity, 1.5_f32);
+
+        assert_eq!(scene.remove_component::<i32>(entity), Some(32));
+        assert_eq!(scene.remove_component::<u8>(entity), None);
+
+        assert!(!scene.component_exists::<i32>(entity));
+        assert!(scene.component_exists::<f32>(entity));
+    }
+
+    #[test]
+    fn deleting_an_entity_from_a_scene_keeps_every_pool_consistent() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, 10_i32);
+        let entity2 = scene.entity();
+        scene.component(entity2, 20_i32);
+        scene.component(entity2, 2.0_f32);
+        let entity3 = scene.entity();
+        scene.component(entity3, 30_i32);
+        scene.component(entity3, 3.0_f32);
+
+        scene.delete(entity1);
+
+        assert_eq!(
+            scene.get::<i32>(),
+            &ComponentPool {
+                num_components: 2,
+
+                sparse_array: vec![None, Some(1), Some(0)],
+                packed_array: vec![2, 1],
+                component_array: vec![30, 20],
+
+                changed: vec![false, true, true],
+            },
+        );
+
+        scene.delete(entity3);
+
+        assert_eq!(
+            scene.get::<i32>(),
+            &ComponentPool {
+                num_components: 1,
+
+                sparse_array: vec![None, Some(0), None],
+                packed_array: vec![1],
+                component_array: vec![20],
+
+                changed: vec![false, true, false],
+            },
+        );
+        assert_eq!(
+            scene.get::<f32>(),
+            &ComponentPool {
+                num_components: 1,
+
+                sparse_array: vec![None, Some(0), None],
+                packed_array: vec![1],
+                component_array: vec![2.0],
+
+                changed: vec![false, true, false],
+            },
+        );
+    }
+
+    #[test]
+    fn clearing_a_scene_resets_it_to_a_new_scene() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.component(entity1, 32_i32);
+        scene.component(entity2, 1.5_f32);
+        scene.delete(entity1);
+
+        scene.clear();
+
+        assert!(!scene.component_pool_exists::<i32>());
+        assert!(!scene.component_pool_exists::<f32>());
+        assert_eq!(scene.next_id, 0);
+        assert_eq!(scene.free_list, Vec::<usize>::new());
+        assert_eq!(scene.entity().raw_id(), 0);
+    }
+
+    #[test]
+    fn handles_taken_before_clearing_stay_dead() {
+        let mut scene = Scene::new();
+
+        let deleted = scene.entity();
+        let live = scene.entity();
+        scene.delete(deleted);
+
+        scene.clear();
+        let reused: Vec<_> = (0..2).map(|_| scene.entity()).collect();
+        scene.component(reused[1], 32_i32);
+
+        assert_eq!(
+            reused
+                .iter()
+                .map(|entity| entity.raw_id())
+                .collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert!(!scene.is_alive(deleted));
+        assert!(!scene.is_alive(live));
+        assert_eq!(scene.get_component::<i32>(live), None);
+        assert!(!scene.delete(live));
+        assert!(scene.is_alive(reused[1]));
+    }
+
+    #[test]
+    fn clearing_components_empties_only_that_pool() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.component(entity1, 32_i32);
+        scene.component(entity2, 21_i32);
+        scene.component(entity2, 1.5_f32);
+
+        scene.clear_components::<i32>();
+
+        assert_eq!(
+            *scene.get::<i32>(),
+            ComponentPool {
+                num_components: 0,
+
+                sparse_array: vec![],
+                packed_array: vec![],
+                component_array: vec![],
+
+                changed: vec![],
             },
         );
+        assert!(scene.has::<f32>(entity2));
+        assert_eq!(scene.entity_count(), 2);
+
+        scene.component(entity1, 7_i32);
+        assert_eq!(scene.get_component::<i32>(entity1), Some(&7));
+    }
+
+    #[test]
+    fn clearing_a_scene_keeps_its_systems() {
+        let mut scene = Scene::new();
+
+        scene.add_system(|_scene: &mut Scene| {});
+        scene.clear();
+
+        assert_eq!(scene.system_count(), 1);
+    }
+
+    #[test]
+    fn clearing_a_scene_restarts_entity_ids_from_zero() {
+        let mut scene = Scene::new();
+
+        for _ in 0..4 {
+            scene.entity();
+        }
+        scene.delete(2);
+
+        scene.clear();
+
+        assert_eq!(
+            (0..3)
+                .map(|_| scene.entity().raw_id())
+                .collect::<Vec<usize>>(),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn retain_removes_rejected_components_and_returns_their_entities() {
+        let mut pool = ComponentPool::new_with_entity(0, 0_i32);
+        for entity in 1..6 {
+            pool.assign_component(entity, entity as i32);
+        }
+
+        let removed = pool.retain(|health| *health % 2 == 1);
+
+        assert_eq!(removed, vec![4, 2, 0]);
+        assert_eq!(pool.iter().copied().collect::<Vec<i32>>(), vec![3, 1, 5]);
+        for entity in 0..6 {
+            assert_eq!(
+                pool.try_get(entity).copied(),
+                Some(entity as i32).filter(|health| health % 2 == 1)
+            );
+        }
+    }
+
+    #[test]
+    fn retain_keeps_enabled_and_disabled_components_apart() {
+        let mut pool = ComponentPool::new_with_entity(0, 0_i32);
+        for entity in 1..5 {
+            pool.assign_component(entity, entity as i32);
+        }
+        pool.disable_entity(1);
+        pool.disable_entity(2);
+
+        let mut removed = pool.retain(|health| *health != 0 && *health != 2);
+        removed.sort();
+
+        assert_eq!(removed, vec![0, 2]);
+        assert_eq!(pool.num_components, 2);
+        assert_eq!(pool.iter_with_entities().count(), 2);
+        assert!(pool.iter().all(|health| *health == 3 || *health == 4));
+        assert_eq!(pool.try_get(1), Some(&1));
+        assert_eq!(pool.index_of(1), Some(2));
+    }
+
+    #[test]
+    fn deleting_an_entity_twice_does_not_recycle_it_twice() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.delete(entity);
+        scene.delete(entity);
+
+        assert_eq!(scene.free_list, vec![entity.raw_id()]);
+        assert_eq!(scene.generation(entity.raw_id()), 1);
+        assert_eq!(scene.entity().raw_id(), entity.raw_id());
+        assert_eq!(scene.entity().raw_id(), 1);
+    }
+
+    #[test]
+    fn deleting_an_entity_that_was_never_created_does_nothing() {
+        let mut scene = Scene::new();
+
+        scene.entity();
+        scene.delete(5);
+
+        assert_eq!(scene.free_list, Vec::<usize>::new());
+        assert_eq!(scene.entity().raw_id(), 1);
     }
 }
@@ -0,0 +1,267 @@
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::{
+    life::{decree::SpawnCommand, Scene},
+    Engine,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DeferredSpawnProgress {
+    pub id: usize,
+    pub done: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DeferredSpawnComplete {
+    pub id: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeferredSpawnHandle {
+    pub id: usize,
+    cancelled: Rc<Cell<bool>>,
+}
+
+impl DeferredSpawnHandle {
+    // Commands already executed stay in the scene, the rest of the work is dropped before the next one runs.
+    pub fn cancel(&self) {
+        self.cancelled.set(true);
+    }
+}
+
+struct DeferredSpawn {
+    id: usize,
+    work: Box<dyn Iterator<Item = SpawnCommand>>,
+    budget: Duration,
+    done: usize,
+    total: usize,
+    cancelled: Rc<Cell<bool>>,
+}
+
+#[derive(Default)]
+pub struct DeferredSpawns {
+    next_id: usize,
+    queue: VecDeque<DeferredSpawn>,
+}
+
+impl Scene {
+    // `total` in progress events is taken from the iterator's size hint.
+    pub fn spawn_deferred<I>(&mut self, work: I, budget: Duration) -> DeferredSpawnHandle
+    where
+        I: Iterator<Item = SpawnCommand> + 'static,
+    {
+        let (lower, upper) = work.size_hint();
+        let cancelled = Rc::new(Cell::new(false));
+
+        if !self.resource_exists::<DeferredSpawns>() {
+            self.insert_resource(DeferredSpawns::default());
+        }
+        let spawns = self.resource_mut::<DeferredSpawns>();
+        let id = spawns.next_id;
+        spawns.next_id += 1;
+
+        spawns.queue.push_back(DeferredSpawn {
+            id,
+            work: Box::new(work),
+            budget,
+            done: 0,
+            total: upper.unwrap_or(lower),
+            cancelled: cancelled.clone(),
+        });
+
+        DeferredSpawnHandle { id, cancelled }
+    }
+
+    pub fn pending_deferred_spawns(&self) -> usize {
+        self.try_resource::<DeferredSpawns>()
+            .map_or(0, |spawns| spawns.queue.len())
+    }
+
+    pub fn advance_deferred_spawns(&mut self) {
+        let start = Instant::now();
+
+        self.advance_deferred_spawns_with(|_executed, budget| start.elapsed() >= budget);
+    }
+
+    // Runs queued work in order until `exhausted(executed, budget)` says the frame is over. At least one
+    // command runs every frame so a budget smaller than a single command still makes progress.
+    pub fn advance_deferred_spawns_with<F>(&mut self, mut exhausted: F)
+    where
+        F: FnMut(usize, Duration) -> bool,
+    {
+        let mut executed = 0;
+
+        while let Some(mut spawn) = self
+            .try_resource_mut::<DeferredSpawns>()
+            .and_then(|spawns| spawns.queue.pop_front())
+        {
+            if spawn.cancelled.get() {
+                continue;
+            }
+
+            let done_before = spawn.done;
+            let mut finished = false;
+
+            while executed == 0 || !exhausted(executed, spawn.budget) {
+                match spawn.work.next() {
+                    Some(command) => {
+                        command(self);
+                        spawn.done += 1;
+                        executed += 1;
+                    }
+                    None => {
+                        finished = true;
+                        break;
+                    }
+                }
+
+                if spawn.cancelled.get() {
+                    break;
+                }
+            }
+
+            if spawn.done > done_before {
+                self.emit(DeferredSpawnProgress {
+                    id: spawn.id,
+                    done: spawn.done,
+                    total: spawn.total.max(spawn.done),
+                });
+            }
+
+            if finished {
+                self.emit(DeferredSpawnComplete { id: spawn.id });
+            } else if !spawn.cancelled.get() {
+                self.resource_mut::<DeferredSpawns>()
+                    .queue
+                    .push_front(spawn);
+                break;
+            }
+        }
+    }
+}
+
+impl Engine {
+    pub fn spawn_deferred<I>(&mut self, work: I, budget: Duration) -> DeferredSpawnHandle
+    where
+        I: Iterator<Item = SpawnCommand> + 'static,
+    {
+        self.scene.spawn_deferred(work, budget)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::life::{
+        caravan::{DeferredSpawnComplete, DeferredSpawnHandle, DeferredSpawnProgress},
+        decree::spawn_command,
+        Scene,
+    };
+
+    const BUDGET: Duration = Duration::from_micros(1);
+
+    fn frame(scene: &mut Scene) {
+        scene.clear_events();
+        scene.advance_deferred_spawns_with(|executed, _budget| executed >= 300);
+    }
+
+    fn level(scene: &mut Scene) -> DeferredSpawnHandle {
+        let work = (0..1000).map(|value| spawn_command((value,)));
+
+        scene.spawn_deferred(work, BUDGET)
+    }
+
+    #[test]
+    fn deferred_spawns_are_spread_across_frames() {
+        let mut scene = Scene::new();
+        level(&mut scene);
+
+        let mut counts = Vec::new();
+        for _ in 0..4 {
+            frame(&mut scene);
+            counts.push(scene.component_count::<i32>());
+        }
+
+        assert_eq!(counts, vec![300, 600, 900, 1000]);
+        assert_eq!(scene.get_component::<i32>(999), Some(&999));
+        assert_eq!(scene.pending_deferred_spawns(), 0);
+    }
+
+    #[test]
+    fn deferred_spawns_report_progress_and_completion() {
+        let mut scene = Scene::new();
+        let handle = level(&mut scene);
+
+        frame(&mut scene);
+        assert_eq!(
+            scene.events::<DeferredSpawnProgress>().collect::<Vec<_>>(),
+            vec![&DeferredSpawnProgress {
+                id: handle.id,
+                done: 300,
+                total: 1000
+            }]
+        );
+        assert_eq!(scene.events::<DeferredSpawnComplete>().count(), 0);
+
+        frame(&mut scene);
+        frame(&mut scene);
+        frame(&mut scene);
+        assert_eq!(
+            scene.events::<DeferredSpawnProgress>().collect::<Vec<_>>(),
+            vec![&DeferredSpawnProgress {
+                id: handle.id,
+                done: 1000,
+                total: 1000
+            }]
+        );
+        assert_eq!(
+            scene.events::<DeferredSpawnComplete>().collect::<Vec<_>>(),
+            vec![&DeferredSpawnComplete { id: handle.id }]
+        );
+    }
+
+    #[test]
+    fn cancelling_stops_between_frames() {
+        let mut scene = Scene::new();
+        let handle = level(&mut scene);
+
+        frame(&mut scene);
+        handle.cancel();
+        frame(&mut scene);
+
+        assert_eq!(scene.component_count::<i32>(), 300);
+        assert_eq!(scene.events::<DeferredSpawnComplete>().count(), 0);
+        assert_eq!(scene.pending_deferred_spawns(), 0);
+    }
+
+    #[test]
+    fn a_spent_budget_still_runs_one_command() {
+        let mut scene = Scene::new();
+        level(&mut scene);
+
+        scene.advance_deferred_spawns_with(|_executed, _budget| true);
+
+        assert_eq!(scene.component_count::<i32>(), 1);
+    }
+
+    #[test]
+    fn later_work_waits_for_earlier_work() {
+        let mut scene = Scene::new();
+        level(&mut scene);
+        scene.spawn_deferred(std::iter::once(spawn_command((1.5_f32,))), BUDGET);
+
+        frame(&mut scene);
+        frame(&mut scene);
+        frame(&mut scene);
+        assert_eq!(scene.component_count::<f32>(), 0);
+
+        frame(&mut scene);
+        assert_eq!(scene.component_count::<f32>(), 1);
+        assert_eq!(scene.events::<DeferredSpawnComplete>().count(), 2);
+    }
+}
@@ -0,0 +1,320 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::sync::{OnceLock, RwLock};
+
+use crate::life::ComponentPoolTrait;
+
+// TypeIds are already hashes, so they are used as-is instead of being hashed again.
+#[derive(Default)]
+pub struct TypeIdHasher {
+    hash: u64,
+}
+
+impl Hasher for TypeIdHasher {
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash = self.hash.rotate_left(8) ^ u64::from(*byte);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.hash ^= value;
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.hash ^= value as u64 ^ (value >> 64) as u64;
+    }
+}
+
+type TypeIndices = HashMap<TypeId, usize, BuildHasherDefault<TypeIdHasher>>;
+
+thread_local! {
+    static CACHED_INDICES: RefCell<TypeIndices> = RefCell::new(TypeIndices::default());
+}
+
+// Hands out one compact index per TypeId, shared by every Scene. Each thread caches the
+// indices it has seen, so lookups only take the global lock the first time.
+pub struct TypeIdMap;
+
+impl TypeIdMap {
+    fn indices() -> &'static RwLock<TypeIndices> {
+        static INDICES: OnceLock<RwLock<TypeIndices>> = OnceLock::new();
+
+        INDICES.get_or_init(|| RwLock::new(TypeIndices::default()))
+    }
+
+    pub fn get(type_id: &TypeId) -> Option<usize> {
+        if let Some(index) = CACHED_INDICES.with(|cached| cached.borrow().get(type_id).copied()) {
+            return Some(index);
+        }
+
+        let index = Self::indices().read().unwrap().get(type_id).copied()?;
+        CACHED_INDICES.with(|cached| cached.borrow_mut().insert(*type_id, index));
+
+        Some(index)
+    }
+
+    pub fn index(type_id: TypeId) -> usize {
+        if let Some(index) = Self::get(&type_id) {
+            return index;
+        }
+
+        let mut indices = Self::indices().write().unwrap();
+        let next = indices.len();
+
+        *indices.entry(type_id).or_insert(next)
+    }
+}
+
+// Each pool sits in its own RefCell so a Scene::pools view can borrow them independently.
+type Slot = Option<(TypeId, RefCell<Box<dyn ComponentPoolTrait>>)>;
+
+// SAFETY: cells are only borrowed through Scene::pools, which holds the Scene mutably for as long
+// as any of its borrows live, so no cell is borrowed while the registry is shared.
+fn shared(component_pool: &RefCell<Box<dyn ComponentPoolTrait>>) -> &dyn ComponentPoolTrait {
+    unsafe { component_pool.try_borrow_unguarded() }
+        .expect("Error: Component pool is borrowed outside of a pools view - Ignition")
+        .as_ref()
+}
+
+#[derive(Default)]
+pub struct PoolRegistry {
+    pools: Vec<Slot>,
+    len: usize,
+}
+
+impl PoolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, type_id: &TypeId) -> Option<&dyn ComponentPoolTrait> {
+        let (_type_id, component_pool) = self.pools.get(TypeIdMap::get(type_id)?)?.as_ref()?;
+
+        Some(shared(component_pool))
+    }
+
+    pub fn get_mut(&mut self, type_id: &TypeId) -> Option<&mut Box<dyn ComponentPoolTrait>> {
+        let (_type_id, component_pool) = self.pools.get_mut(TypeIdMap::get(type_id)?)?.as_mut()?;
+
+        Some(component_pool.get_mut())
+    }
+
+    pub fn contains_key(&self, type_id: &TypeId) -> bool {
+        self.get(type_id).is_some()
+    }
+
+    pub fn insert(
+        &mut self,
+        type_id: TypeId,
+        component_pool: Box<dyn ComponentPoolTrait>,
+    ) -> Option<Box<dyn ComponentPoolTrait>> {
+        let index = TypeIdMap::index(type_id);
+        if index >= self.pools.len() {
+            self.pools.resize_with(index + 1, || None);
+        }
+
+        let previous = self.pools[index].replace((type_id, RefCell::new(component_pool)));
+        if previous.is_none() {
+            self.len += 1;
+        }
+
+        previous.map(|(_type_id, component_pool)| component_pool.into_inner())
+    }
+
+    pub fn remove(&mut self, type_id: &TypeId) -> Option<Box<dyn ComponentPoolTrait>> {
+        let (_type_id, component_pool) = self.pools.get_mut(TypeIdMap::get(type_id)?)?.take()?;
+        self.len -= 1;
+
+        Some(component_pool.into_inner())
+    }
+
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&TypeId, &mut Box<dyn ComponentPoolTrait>) -> bool,
+    {
+        for slot in self.pools.iter_mut() {
+            if let Some((type_id, component_pool)) = slot {
+                if !keep(type_id, component_pool.get_mut()) {
+                    *slot = None;
+                    self.len -= 1;
+                }
+            }
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&TypeId, &dyn ComponentPoolTrait)> {
+        self.pools
+            .iter()
+            .flatten()
+            .map(|(type_id, component_pool)| (type_id, shared(component_pool)))
+    }
+
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (&TypeId, &mut Box<dyn ComponentPoolTrait>)> {
+        self.pools
+            .iter_mut()
+            .flatten()
+            .map(|(type_id, component_pool)| (&*type_id, component_pool.get_mut()))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &dyn ComponentPoolTrait> {
+        self.iter().map(|(_type_id, component_pool)| component_pool)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get_disjoint_mut<const N: usize>(
+        &mut self,
+        type_ids: [&TypeId; N],
+    ) -> [Option<&mut Box<dyn ComponentPoolTrait>>; N] {
+        for (position, type_id) in type_ids.iter().enumerate() {
+            assert!(
+                !type_ids[..position].contains(type_id),
+                "Error: Component pools requested mutably more than once - Ignition"
+            );
+        }
+
+        let indices = type_ids.map(TypeIdMap::get);
+        let mut found: [Option<&mut Box<dyn ComponentPoolTrait>>; N] =
+            std::array::from_fn(|_| None);
+
+        for (index, slot) in self.pools.iter_mut().enumerate() {
+            if let Some(position) = indices.iter().position(|&found| found == Some(index)) {
+                found[position] = slot
+                    .as_mut()
+                    .map(|(_type_id, component_pool)| component_pool.get_mut());
+            }
+        }
+
+        found
+    }
+
+    // Only for Scene::pools, borrowing a cell anywhere else would alias the plain references above.
+    pub(crate) fn cell(&self, type_id: &TypeId) -> Option<&RefCell<Box<dyn ComponentPoolTrait>>> {
+        let (_type_id, component_pool) = self.pools.get(TypeIdMap::get(type_id)?)?.as_ref()?;
+
+        Some(component_pool)
+    }
+}
+
+impl FromIterator<(TypeId, Box<dyn ComponentPoolTrait>)> for PoolRegistry {
+    fn from_iter<I: IntoIterator<Item = (TypeId, Box<dyn ComponentPoolTrait>)>>(iter: I) -> Self {
+        let mut registry = PoolRegistry::new();
+        for (type_id, component_pool) in iter {
+            registry.insert(type_id, component_pool);
+        }
+
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use crate::life::{
+        catalogue::{PoolRegistry, TypeIdMap},
+        ComponentPool, ComponentPoolTrait,
+    };
+
+    fn pool<G: 'static>(entity: usize, component: G) -> Box<dyn ComponentPoolTrait> {
+        Box::new(ComponentPool::new_with_entity(entity, component))
+    }
+
+    #[test]
+    fn every_type_gets_one_stable_index() {
+        let index = TypeIdMap::index(TypeId::of::<u16>());
+
+        assert_eq!(TypeIdMap::index(TypeId::of::<u16>()), index);
+        assert_eq!(TypeIdMap::get(&TypeId::of::<u16>()), Some(index));
+        assert_ne!(TypeIdMap::index(TypeId::of::<i16>()), index);
+    }
+
+    #[test]
+    fn inserted_pools_can_be_found_again() {
+        let mut registry = PoolRegistry::new();
+
+        assert!(registry
+            .insert(TypeId::of::<i32>(), pool(0, 32_i32))
+            .is_none());
+        assert!(registry
+            .insert(TypeId::of::<i32>(), pool(1, 21_i32))
+            .is_some());
+
+        assert_eq!(registry.len(), 1);
+        assert!(registry.contains_key(&TypeId::of::<i32>()));
+        assert!(!registry.contains_key(&TypeId::of::<f64>()));
+        assert_eq!(
+            registry
+                .get(&TypeId::of::<i32>())
+                .unwrap()
+                .as_any()
+                .downcast_ref::<ComponentPool<i32>>()
+                .unwrap()
+                .packed_array,
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn removing_and_retaining_pools_keeps_the_count() {
+        let mut registry = PoolRegistry::new();
+
+        registry.insert(TypeId::of::<i32>(), pool(0, 32_i32));
+        registry.insert(TypeId::of::<u8>(), pool(0, 8_u8));
+        registry.insert(TypeId::of::<char>(), pool(0, 'c'));
+
+        assert!(registry.remove(&TypeId::of::<u8>()).is_some());
+        assert!(registry.remove(&TypeId::of::<u8>()).is_none());
+        registry.retain(|type_id, _component_pool| *type_id != TypeId::of::<char>());
+
+        assert_eq!(registry.len(), 1);
+        assert_eq!(
+            registry
+                .iter()
+                .map(|(type_id, _)| *type_id)
+                .collect::<Vec<_>>(),
+            vec![TypeId::of::<i32>()]
+        );
+    }
+
+    #[test]
+    fn disjoint_lookups_return_pools_in_request_order() {
+        let mut registry = PoolRegistry::new();
+
+        registry.insert(TypeId::of::<i32>(), pool(0, 32_i32));
+        registry.insert(TypeId::of::<u8>(), pool(0, 8_u8));
+
+        let [u8_pool, f64_pool, i32_pool] = registry.get_disjoint_mut([
+            &TypeId::of::<u8>(),
+            &TypeId::of::<f64>(),
+            &TypeId::of::<i32>(),
+        ]);
+
+        assert!(u8_pool.unwrap().as_any().is::<ComponentPool<u8>>());
+        assert!(f64_pool.is_none());
+        assert!(i32_pool.unwrap().as_any().is::<ComponentPool<i32>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Component pools requested mutably more than once - Ignition")]
+    fn disjoint_lookups_refuse_duplicates() {
+        let mut registry = PoolRegistry::new();
+
+        registry.get_disjoint_mut([&TypeId::of::<i32>(), &TypeId::of::<i32>()]);
+    }
+}
@@ -0,0 +1,620 @@
+use std::any::TypeId;
+use std::marker::PhantomData;
+use std::slice::Iter;
+
+use crate::life::{ComponentPool, Scene};
+
+impl Scene {
+    pub fn query<Q: Query>(&self) -> QueryIter<'_, Q> {
+        QueryIter::new(self, Q::pools(self))
+    }
+
+    pub fn query_mut<Q: QueryMut>(&mut self) -> QueryMutIter<'_, Q> {
+        QueryMutIter::new(Q::pools(self))
+    }
+
+    pub fn get_pair<A: 'static, B: 'static>(&self) -> impl Iterator<Item = (usize, &A, &B)> {
+        self.query::<(A, B)>()
+    }
+
+    // Only A is marked as changed, B is lent out shared.
+    pub fn get_pair_mut<A: 'static, B: 'static>(
+        &mut self,
+    ) -> impl Iterator<Item = (usize, &mut A, &B)> {
+        let [a, b] = self
+            .component_pools
+            .get_disjoint_mut([&TypeId::of::<A>(), &TypeId::of::<B>()]);
+        let a = a.and_then(|pool| pool.as_any_mut().downcast_mut::<ComponentPool<A>>());
+        let b = b.and_then(|pool| pool.as_any().downcast_ref::<ComponentPool<B>>());
+
+        a.zip(b).into_iter().flat_map(|(a, b)| {
+            a.prolong_changed();
+            let changed = &mut a.changed;
+            let (left, _right) = a.component_array.split_at_mut(a.num_components);
+
+            a.packed_array
+                .iter()
+                .copied()
+                .zip(left.iter_mut())
+                .filter_map(move |(entity, component)| {
+                    let other = b.get_enabled(entity)?;
+                    changed[entity] = true;
+
+                    Some((entity, component, other))
+                })
+        })
+    }
+}
+
+impl<G> ComponentPool<G> {
+    pub fn enabled_entities(&self) -> &[usize] {
+        &self.packed_array[..self.num_components]
+    }
+
+    pub fn get_enabled(&self, entity: usize) -> Option<&G> {
+        match self.index_of(entity) {
+            Some(index) if index < self.num_components => self.component_array.get(index),
+            _ => None,
+        }
+    }
+
+    pub fn is_enabled(&self, entity: usize) -> bool {
+        enabled_in(&self.sparse_array, self.num_components, entity)
+    }
+}
+
+fn enabled_in(sparse_array: &[Option<u32>], num_components: usize, entity: usize) -> bool {
+    matches!(sparse_array.get(entity), Some(&Some(index)) if (index as usize) < num_components)
+}
+
+pub trait Query {
+    type Pools<'a>: Copy;
+    type Item<'a>;
+
+    fn pools(scene: &Scene) -> Option<Self::Pools<'_>>;
+    fn entities<'a>(pools: Self::Pools<'a>) -> &'a [usize];
+    fn fetch<'a>(pools: Self::Pools<'a>, entity: usize) -> Option<Self::Item<'a>>;
+}
+
+pub struct QueryIter<'a, Q: Query> {
+    scene: &'a Scene,
+    pools: Option<Q::Pools<'a>>,
+    entities: Iter<'a, usize>,
+    filters: Vec<QueryFilter<'a>>,
+
+    query: PhantomData<Q>,
+}
+
+pub struct QueryFilter<'a> {
+    num_components: usize,
+    sparse_array: &'a [Option<u32>],
+    required: bool,
+}
+
+impl<'a> QueryFilter<'a> {
+    pub fn matches(&self, entity: usize) -> bool {
+        enabled_in(self.sparse_array, self.num_components, entity) == self.required
+    }
+}
+
+impl<'a, Q: Query> QueryIter<'a, Q> {
+    pub fn new(scene: &'a Scene, pools: Option<Q::Pools<'a>>) -> Self {
+        let entities = match pools {
+            Some(pools) => Q::entities(pools).iter(),
+            None => [].iter(),
+        };
+
+        Self {
+            scene,
+            pools,
+            entities,
+            filters: Vec::new(),
+
+            query: PhantomData,
+        }
+    }
+
+    pub fn with<G: 'static>(self) -> Self {
+        self.filter::<G>(true)
+    }
+
+    pub fn without<G: 'static>(self) -> Self {
+        self.filter::<G>(false)
+    }
+
+    fn filter<G: 'static>(mut self, required: bool) -> Self {
+        match self.scene.try_get::<G>() {
+            Some(component_pool) => self.filters.push(QueryFilter {
+                num_components: component_pool.num_components,
+                sparse_array: &component_pool.sparse_array,
+                required,
+            }),
+            None if required => self.pools = None,
+            None => {}
+        }
+
+        self
+    }
+}
+
+impl<'a, Q: Query> Iterator for QueryIter<'a, Q> {
+    type Item = Q::Item<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pools = self.pools?;
+        let filters = &self.filters;
+
+        self.entities
+            .by_ref()
+            .filter(|&&entity| filters.iter().all(|filter| filter.matches(entity)))
+            .find_map(|&entity| Q::fetch(pools, entity))
+    }
+}
+
+pub trait QueryMut {
+    type Pools<'a>;
+    type Item<'a>;
+
+    fn pools(scene: &mut Scene) -> Option<Self::Pools<'_>>;
+    fn entities<'a>(pools: &Self::Pools<'a>) -> &'a [usize];
+
+    /// # Safety
+    ///
+    /// Each entity may be fetched at most once from the same pools, the items borrow the components
+    /// mutably for as long as the pools live.
+    unsafe fn fetch<'a>(pools: &mut Self::Pools<'a>, entity: usize) -> Option<Self::Item<'a>>;
+}
+
+pub struct QueryMutIter<'a, Q: QueryMut> {
+    pools: Option<Q::Pools<'a>>,
+    entities: Iter<'a, usize>,
+}
+
+impl<'a, Q: QueryMut> QueryMutIter<'a, Q> {
+    pub fn new(pools: Option<Q::Pools<'a>>) -> Self {
+        let entities = match &pools {
+            Some(pools) => Q::entities(pools).iter(),
+            None => [].iter(),
+        };
+
+        Self { pools, entities }
+    }
+}
+
+impl<'a, Q: QueryMut> Iterator for QueryMutIter<'a, Q> {
+    type Item = Q::Item<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pools = self.pools.as_mut()?;
+
+        // SAFETY: the entities come from a packed array, which holds every entity once, and the
+        // iterator moves past each of them as it is fetched.
+        self.entities
+            .by_ref()
+            .find_map(|&entity| unsafe { Q::fetch(pools, entity) })
+    }
+}
+
+pub struct PoolView<'a, G> {
+    num_components: usize,
+
+    sparse_array: &'a [Option<u32>],
+    packed_array: &'a [usize],
+    component_array: *mut G,
+
+    changed: &'a mut [bool],
+
+    pool: PhantomData<&'a mut ComponentPool<G>>,
+}
+
+impl<'a, G> PoolView<'a, G> {
+    pub fn new(pool: &'a mut ComponentPool<G>) -> Self {
+        pool.prolong_changed();
+
+        Self {
+            num_components: pool.num_components,
+
+            sparse_array: &pool.sparse_array,
+            packed_array: &pool.packed_array,
+            component_array: pool.component_array.as_mut_ptr(),
+
+            changed: &mut pool.changed,
+
+            pool: PhantomData,
+        }
+    }
+
+    pub fn enabled_entities(&self) -> &'a [usize] {
+        &self.packed_array[..self.num_components]
+    }
+
+    /// # Safety
+    ///
+    /// The same entity must not be fetched twice from one view, the first borrow lasts for 'a.
+    pub unsafe fn get_enabled(&mut self, entity: usize) -> Option<&'a mut G> {
+        match self.sparse_array.get(entity) {
+            Some(&Some(index)) if (index as usize) < self.num_components => {
+                self.changed[entity] = true;
+                // SAFETY: the view holds the pool's unique borrow for 'a, and the caller promises
+                // not to ask for this entity again.
+                Some(unsafe { &mut *self.component_array.add(index as usize) })
+            }
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_query {
+    ($($G:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($G: 'static),+> Query for ($($G,)+) {
+            type Pools<'a> = ($(&'a ComponentPool<$G>,)+);
+            type Item<'a> = (usize, $(&'a $G),+);
+
+            fn pools(scene: &Scene) -> Option<Self::Pools<'_>> {
+                Some(($(scene.try_get::<$G>()?,)+))
+            }
+
+            fn entities<'a>(pools: Self::Pools<'a>) -> &'a [usize] {
+                let ($($G,)+) = pools;
+
+                [$($G.enabled_entities()),+]
+                    .into_iter()
+                    .min_by_key(|entities| entities.len())
+                    .unwrap()
+            }
+
+            fn fetch<'a>(pools: Self::Pools<'a>, entity: usize) -> Option<Self::Item<'a>> {
+                let ($($G,)+) = pools;
+
+                Some((entity, $($G.get_enabled(entity)?),+))
+            }
+        }
+    };
+}
+
+macro_rules! impl_query_mut {
+    ($($G:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($G: 'static),+> QueryMut for ($($G,)+) {
+            type Pools<'a> = ($(PoolView<'a, $G>,)+);
+            type Item<'a> = (usize, $(&'a mut $G),+);
+
+            fn pools(scene: &mut Scene) -> Option<Self::Pools<'_>> {
+                let [$($G),+] = scene
+                    .component_pools
+                    .get_disjoint_mut([$(&TypeId::of::<$G>()),+]);
+
+                Some(($(PoolView::new($G?.as_any_mut().downcast_mut::<ComponentPool<$G>>()?),)+))
+            }
+
+            fn entities<'a>(pools: &Self::Pools<'a>) -> &'a [usize] {
+                let ($($G,)+) = pools;
+
+                [$($G.enabled_entities()),+]
+                    .into_iter()
+                    .min_by_key(|entities| entities.len())
+                    .unwrap()
+            }
+
+            unsafe fn fetch<'a>(pools: &mut Self::Pools<'a>, entity: usize) -> Option<Self::Item<'a>> {
+                let ($($G,)+) = pools;
+
+                // SAFETY: forwarded from the caller, each pool is a distinct type and so a distinct view.
+                Some((entity, $(unsafe { $G.get_enabled(entity)? }),+))
+            }
+        }
+    };
+}
+
+impl_query!(A);
+impl_query!(A, B);
+impl_query!(A, B, C);
+impl_query!(A, B, C, D);
+
+impl_query_mut!(A);
+impl_query_mut!(A, B);
+impl_query_mut!(A, B, C);
+impl_query_mut!(A, B, C, D);
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    #[derive(Debug, PartialEq)]
+    struct Position(f32, f32);
+
+    #[derive(Debug, PartialEq)]
+    struct Velocity(f32, f32);
+
+    #[test]
+    fn querying_two_components_yields_only_entities_owning_both() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, Position(0.0, 0.0));
+
+        let entity2 = scene.entity();
+        scene.component(entity2, Position(1.0, 1.0));
+        scene.component(entity2, Velocity(0.5, 0.5));
+
+        let entity3 = scene.entity();
+        scene.component(entity3, Velocity(2.0, 2.0));
+
+        let entity4 = scene.entity();
+        scene.component(entity4, Velocity(3.0, 3.0));
+        scene.component(entity4, Position(3.0, 3.0));
+
+        assert_eq!(
+            scene.query::<(Position, Velocity)>().collect::<Vec<_>>(),
+            vec![
+                (entity2.raw_id(), &Position(1.0, 1.0), &Velocity(0.5, 0.5)),
+                (entity4.raw_id(), &Position(3.0, 3.0), &Velocity(3.0, 3.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn querying_a_missing_component_pool_yields_nothing() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.component(entity, Position(0.0, 0.0));
+
+        assert_eq!(scene.query::<(Position, Velocity)>().count(), 0);
+    }
+
+    #[test]
+    fn querying_skips_disabled_components() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, Position(0.0, 0.0));
+        scene.component(entity1, Velocity(0.0, 0.0));
+
+        let entity2 = scene.entity();
+        scene.component(entity2, Position(1.0, 1.0));
+        scene.component(entity2, Velocity(1.0, 1.0));
+
+        scene.disable::<Velocity>(entity1);
+
+        assert_eq!(
+            scene
+                .query::<(Position, Velocity)>()
+                .map(|(entity, _, _)| entity)
+                .collect::<Vec<usize>>(),
+            vec![entity2.raw_id()]
+        );
+    }
+
+    #[test]
+    fn querying_a_single_component_yields_entity_and_component() {
+        let mut scene = Scene::new();
+
+        scene.entity();
+        let entity = scene.entity();
+        scene.component(entity, 34_i32);
+
+        assert_eq!(
+            scene.query::<(i32,)>().collect::<Vec<_>>(),
+            vec![(entity.raw_id(), &34)]
+        );
+    }
+
+    #[test]
+    fn querying_three_components_intersects_all_pools() {
+        let mut scene = Scene::new();
+
+        for i in 0..6 {
+            let entity = scene.entity();
+
+            if i % 2 == 0 {
+                scene.component(entity, i);
+            }
+            if i % 3 == 0 {
+                scene.component(entity, i as u32);
+            }
+            scene.component(entity, i as f32);
+        }
+
+        assert_eq!(
+            scene.query::<(i32, u32, f32)>().collect::<Vec<_>>(),
+            vec![(0, &0, &0, &0.0)]
+        );
+    }
+
+    #[test]
+    fn mutable_query_allows_writing_to_every_matched_component() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, Position(0.0, 0.0));
+        scene.component(entity1, Velocity(1.0, 2.0));
+
+        let entity2 = scene.entity();
+        scene.component(entity2, Position(5.0, 5.0));
+
+        let entity3 = scene.entity();
+        scene.component(entity3, Velocity(-1.0, 0.5));
+        scene.component(entity3, Position(1.0, 1.0));
+
+        for (_entity, position, velocity) in scene.query_mut::<(Position, Velocity)>() {
+            position.0 += velocity.0;
+            position.1 += velocity.1;
+
+            velocity.0 = 0.0;
+        }
+
+        assert_eq!(
+            scene.get::<Position>().iter().collect::<Vec<_>>(),
+            vec![
+                &Position(1.0, 2.0),
+                &Position(5.0, 5.0),
+                &Position(0.0, 1.5)
+            ]
+        );
+        assert_eq!(
+            scene.get::<Velocity>().iter().collect::<Vec<_>>(),
+            vec![&Velocity(0.0, 2.0), &Velocity(0.0, 0.5)]
+        );
+    }
+
+    #[test]
+    fn mutable_query_on_a_missing_component_pool_yields_nothing() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.component(entity, Position(0.0, 0.0));
+
+        assert_eq!(scene.query_mut::<(Position, Velocity)>().count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mutable_query_requesting_the_same_component_twice_panics() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.component(entity, Position(0.0, 0.0));
+
+        scene.query_mut::<(Position, Position)>().count();
+    }
+
+    #[test]
+    fn getting_a_pair_yields_only_the_entity_owning_both_components() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, Position(0.0, 0.0));
+
+        let entity2 = scene.entity();
+        scene.component(entity2, Velocity(1.0, 1.0));
+
+        let entity3 = scene.entity();
+        scene.component(entity3, Position(2.0, 2.0));
+        scene.component(entity3, Velocity(3.0, 3.0));
+
+        assert_eq!(
+            scene.get_pair::<Position, Velocity>().collect::<Vec<_>>(),
+            vec![(entity3.raw_id(), &Position(2.0, 2.0), &Velocity(3.0, 3.0))]
+        );
+    }
+
+    #[test]
+    fn getting_a_mutable_pair_allows_moving_positions_by_velocities() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, Position(0.0, 0.0));
+
+        let entity2 = scene.entity();
+        scene.component(entity2, Position(2.0, 2.0));
+        scene.component(entity2, Velocity(3.0, -1.0));
+
+        for (_entity, position, velocity) in scene.get_pair_mut::<Position, Velocity>() {
+            position.0 += velocity.0;
+            position.1 += velocity.1;
+        }
+
+        assert_eq!(
+            scene.get::<Position>().iter().collect::<Vec<_>>(),
+            vec![&Position(0.0, 0.0), &Position(5.0, 1.0)]
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Player;
+
+    #[derive(Debug, PartialEq)]
+    struct Frozen;
+
+    fn filtered_scene() -> Scene {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, Position(0.0, 0.0));
+        scene.component(entity1, Player);
+
+        let entity2 = scene.entity();
+        scene.component(entity2, Position(1.0, 1.0));
+        scene.component(entity2, Player);
+        scene.component(entity2, Frozen);
+
+        let entity3 = scene.entity();
+        scene.component(entity3, Position(2.0, 2.0));
+        scene.component(entity3, Frozen);
+
+        scene
+    }
+
+    fn entities<'a>(iter: impl Iterator<Item = (usize, &'a Position)>) -> Vec<usize> {
+        iter.map(|(entity, _)| entity).collect()
+    }
+
+    #[test]
+    fn filtering_a_query_with_a_component_keeps_only_its_owners() {
+        let scene = filtered_scene();
+
+        assert_eq!(
+            entities(scene.query::<(Position,)>().with::<Player>()),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn filtering_a_query_without_a_component_drops_its_owners() {
+        let scene = filtered_scene();
+
+        assert_eq!(
+            entities(scene.query::<(Position,)>().without::<Frozen>()),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn combining_query_filters_applies_all_of_them() {
+        let scene = filtered_scene();
+
+        assert_eq!(
+            entities(
+                scene
+                    .query::<(Position,)>()
+                    .with::<Frozen>()
+                    .without::<Player>()
+            ),
+            vec![2]
+        );
+        assert_eq!(
+            entities(
+                scene
+                    .query::<(Position,)>()
+                    .with::<Player>()
+                    .with::<Frozen>()
+            ),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn filtering_a_query_by_missing_component_pools() {
+        let scene = filtered_scene();
+
+        assert_eq!(scene.query::<(Position,)>().with::<Velocity>().count(), 0);
+        assert_eq!(
+            scene.query::<(Position,)>().without::<Velocity>().count(),
+            3
+        );
+    }
+
+    #[test]
+    fn filtering_a_query_treats_disabled_components_as_missing() {
+        let mut scene = filtered_scene();
+
+        scene.disable::<Frozen>(1);
+
+        assert_eq!(
+            entities(scene.query::<(Position,)>().without::<Frozen>()),
+            vec![0, 1]
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::any::{type_name, Any, TypeId};
+use std::fmt;
+
+use crate::life::{genesis::entity::EntityKey, Scene};
+
+pub type DynamicInserter = fn(&mut Scene, usize, Box<dyn Any>) -> Result<(), DynamicInsertError>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DynamicInsertError {
+    NotRegistered(TypeId),
+    WrongType { expected: &'static str },
+    StaleEntity,
+}
+
+impl fmt::Display for DynamicInsertError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DynamicInsertError::NotRegistered(type_id) => write!(
+                formatter,
+                "Error: {:?} was not registered for dynamic insertion - Ignition",
+                type_id
+            ),
+            DynamicInsertError::WrongType { expected } => write!(
+                formatter,
+                "Error: Dynamic component is not a {} - Ignition",
+                expected
+            ),
+            DynamicInsertError::StaleEntity => write!(
+                formatter,
+                "Error: Dynamic component was inserted on a deleted entity - Ignition"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DynamicInsertError {}
+
+impl Scene {
+    pub fn register_dynamic<G: 'static>(&mut self) {
+        self.inserters
+            .insert(TypeId::of::<G>(), insert_dynamic::<G>);
+    }
+
+    pub fn component_dynamic(
+        &mut self,
+        entity: impl EntityKey,
+        type_id: TypeId,
+        component: Box<dyn Any>,
+    ) -> Result<(), DynamicInsertError> {
+        let entity = entity
+            .resolve_in(self)
+            .ok_or(DynamicInsertError::StaleEntity)?;
+        let insert = *self
+            .inserters
+            .get(&type_id)
+            .ok_or(DynamicInsertError::NotRegistered(type_id))?;
+
+        insert(self, entity, component)
+    }
+}
+
+fn insert_dynamic<G: 'static>(
+    scene: &mut Scene,
+    entity: usize,
+    component: Box<dyn Any>,
+) -> Result<(), DynamicInsertError> {
+    let component = component
+        .downcast::<G>()
+        .map_err(|_| DynamicInsertError::WrongType {
+            expected: type_name::<G>(),
+        })?;
+    scene.component(entity, *component);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::{Any, TypeId};
+
+    use crate::life::{chimera::DynamicInsertError, Scene};
+
+    #[test]
+    fn registered_types_can_be_inserted_dynamically() {
+        let mut scene = Scene::new();
+        scene.register_dynamic::<i32>();
+        let entity = scene.entity();
+
+        let component: Box<dyn Any> = Box::new(34_i32);
+        scene
+            .component_dynamic(entity, TypeId::of::<i32>(), component)
+            .unwrap();
+
+        assert_eq!(scene.get::<i32>().iter().collect::<Vec<&i32>>(), vec![&34]);
+        assert_eq!(scene.get_component::<i32>(entity), Some(&34));
+    }
+
+    #[test]
+    fn unregistered_types_are_rejected() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+
+        assert_eq!(
+            scene.component_dynamic(entity, TypeId::of::<i32>(), Box::new(34_i32)),
+            Err(DynamicInsertError::NotRegistered(TypeId::of::<i32>()))
+        );
+        assert!(!scene.component_pool_exists::<i32>());
+    }
+
+    #[test]
+    fn boxes_of_the_wrong_type_are_rejected() {
+        let mut scene = Scene::new();
+        scene.register_dynamic::<i32>();
+        let entity = scene.entity();
+
+        assert_eq!(
+            scene.component_dynamic(entity, TypeId::of::<i32>(), Box::new(34_i64)),
+            Err(DynamicInsertError::WrongType { expected: "i32" })
+        );
+        assert!(!scene.component_pool_exists::<i32>());
+    }
+}
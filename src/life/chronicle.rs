@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::life::Scene;
+
+impl Scene {
+    // One line per live entity, e.g. `entity 3: [Shape, Transform]`, sorted by entity then type name.
+    pub fn debug_dump(&self) -> String {
+        let mut owners: BTreeMap<usize, Vec<String>> =
+            self.entities().map(|entity| (entity, Vec::new())).collect();
+
+        for (_, pool) in self.component_pools.iter() {
+            let type_name = short_type_name(pool.type_name());
+            for entity in pool.entities() {
+                if let Some(names) = owners.get_mut(entity) {
+                    names.push(type_name.clone());
+                }
+            }
+        }
+
+        owners
+            .into_iter()
+            .map(|(entity, mut names)| {
+                names.sort();
+                format!("entity {}: [{}]", entity, names.join(", "))
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Debug for Scene {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.debug_dump())
+    }
+}
+
+// Drops module paths everywhere in the name, so `Box<dyn ignition::Renderable>` reads `Box<dyn Renderable>`.
+pub fn short_type_name(type_name: &str) -> String {
+    let mut short = String::with_capacity(type_name.len());
+    let mut segment_start = 0;
+    let mut characters = type_name.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        if character == ':' && characters.peek() == Some(&':') {
+            characters.next();
+            short.truncate(segment_start);
+            continue;
+        }
+
+        short.push(character);
+        if !(character.is_alphanumeric() || character == '_') {
+            segment_start = short.len();
+        }
+    }
+
+    short
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::{chronicle::short_type_name, Scene};
+
+    #[derive(Debug)]
+    struct Shape;
+
+    #[derive(Debug)]
+    struct Transform;
+
+    fn small_scene() -> Scene {
+        let mut scene = Scene::new();
+
+        let empty = scene.entity();
+        let both = scene.entity();
+        let deleted = scene.entity();
+        let shape = scene.entity();
+
+        scene.component(shape, Shape);
+        scene.component(both, Transform);
+        scene.component(both, Shape);
+        scene.component(deleted, Transform);
+        scene.delete(deleted);
+        scene.component(empty, vec![1_u8]);
+        scene.disable::<Vec<u8>>(empty);
+
+        scene
+    }
+
+    #[test]
+    fn dump_lists_components_per_entity() {
+        let dump = small_scene().debug_dump();
+
+        assert!(dump.contains("entity 1: [Shape, Transform]"));
+        assert!(dump.contains("entity 3: [Shape]"));
+        assert!(!dump.contains("entity 2"));
+    }
+
+    #[test]
+    fn dump_is_sorted_by_entity() {
+        assert_eq!(
+            format!("{:?}", small_scene()),
+            "entity 0: [Vec<u8>]\nentity 1: [Shape, Transform]\nentity 3: [Shape]"
+        );
+    }
+
+    #[test]
+    fn type_names_lose_their_module_paths() {
+        assert_eq!(
+            short_type_name(
+                "alloc::boxed::Box<dyn ignition::manifestation::silhouette::Renderable>"
+            ),
+            "Box<dyn Renderable>"
+        );
+        assert_eq!(
+            short_type_name("(f32, core::option::Option<u8>)"),
+            "(f32, Option<u8>)"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use std::time::Duration;
+
+use crate::life::{genesis::entity::EntityKey, Scene};
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Time {
+    pub delta: Duration,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Timer {
+    pub duration: Duration,
+    pub elapsed: Duration,
+    pub repeating: bool,
+    pub paused: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TimerFinished(pub usize);
+
+impl Timer {
+    pub fn once(duration: Duration) -> Self {
+        Self {
+            duration,
+            elapsed: Duration::ZERO,
+            repeating: false,
+            paused: false,
+        }
+    }
+
+    pub fn repeating(duration: Duration) -> Self {
+        Self {
+            repeating: true,
+            ..Self::once(duration)
+        }
+    }
+
+    pub fn finished(&self) -> bool {
+        !self.repeating && self.elapsed >= self.duration
+    }
+
+    pub fn tick(&mut self, delta: Duration) -> u32 {
+        if self.paused || self.finished() {
+            return 0;
+        }
+
+        self.elapsed += delta;
+        if self.elapsed < self.duration {
+            return 0;
+        }
+
+        if !self.repeating {
+            self.elapsed = self.duration;
+            return 1;
+        }
+
+        if self.duration.is_zero() {
+            self.elapsed = Duration::ZERO;
+            return 1;
+        }
+
+        let mut fired = 0;
+        while self.elapsed >= self.duration {
+            self.elapsed -= self.duration;
+            fired += 1;
+        }
+
+        fired
+    }
+}
+
+impl Scene {
+    pub fn advance_time(&mut self, delta: Duration) {
+        let time = self.time_mut();
+
+        time.delta = delta;
+        time.elapsed += delta;
+    }
+
+    pub fn timer(&mut self, entity: impl EntityKey, duration: Duration) -> bool {
+        self.vectorized_component(entity, Timer::once(duration))
+    }
+
+    pub fn repeating_timer(&mut self, entity: impl EntityKey, duration: Duration) -> bool {
+        self.vectorized_component(entity, Timer::repeating(duration))
+    }
+
+    pub fn tick_timers(&mut self) {
+        let delta = self
+            .try_resource::<Time>()
+            .map_or(Duration::ZERO, |time| time.delta);
+        let mut finished = Vec::new();
+
+        if let Some(component_pool) = self.try_get_mut::<Vec<Timer>>() {
+            for (entity, timers) in component_pool.iter_with_entities_mut() {
+                for timer in timers.iter_mut() {
+                    for _ in 0..timer.tick(delta) {
+                        finished.push(TimerFinished(entity));
+                    }
+                }
+            }
+        }
+
+        for event in finished {
+            self.emit(event);
+        }
+    }
+
+    fn time_mut(&mut self) -> &mut Time {
+        if !self.resource_exists::<Time>() {
+            self.insert_resource(Time::default());
+        }
+
+        self.resource_mut::<Time>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::life::{
+        clockwork::{Time, Timer, TimerFinished},
+        Scene,
+    };
+
+    fn frame(scene: &mut Scene, millis: u64) -> Vec<TimerFinished> {
+        scene.clear_events();
+        scene.advance_time(Duration::from_millis(millis));
+        scene.tick_timers();
+
+        scene.events::<TimerFinished>().copied().collect()
+    }
+
+    #[test]
+    fn advancing_time_updates_the_time_resource() {
+        let mut scene = Scene::new();
+
+        scene.advance_time(Duration::from_millis(16));
+        scene.advance_time(Duration::from_millis(20));
+
+        assert_eq!(
+            *scene.resource::<Time>(),
+            Time {
+                delta: Duration::from_millis(20),
+                elapsed: Duration::from_millis(36),
+            }
+        );
+    }
+
+    #[test]
+    fn one_shot_timers_fire_once_when_due() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.timer(entity, Duration::from_millis(50));
+
+        assert_eq!(frame(&mut scene, 30), vec![]);
+        assert_eq!(frame(&mut scene, 30), vec![TimerFinished(entity.raw_id())]);
+        assert_eq!(frame(&mut scene, 30), vec![]);
+        assert!(scene.get_component::<Vec<Timer>>(entity).unwrap()[0].finished());
+    }
+
+    #[test]
+    fn repeating_timers_fire_on_every_period() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.repeating_timer(entity, Duration::from_millis(20));
+
+        let fired = (0..10)
+            .map(|_| frame(&mut scene, 10).len())
+            .collect::<Vec<_>>();
+
+        assert_eq!(fired, vec![0, 1, 0, 1, 0, 1, 0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn long_frames_fire_repeating_timers_several_times() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.repeating_timer(entity, Duration::from_millis(10));
+
+        assert_eq!(frame(&mut scene, 35).len(), 3);
+        assert_eq!(frame(&mut scene, 5).len(), 1);
+    }
+
+    #[test]
+    fn paused_timers_do_not_advance() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.timer(entity, Duration::from_millis(20));
+        frame(&mut scene, 10);
+
+        scene.get_component_mut::<Vec<Timer>>(entity).unwrap()[0].paused = true;
+        assert_eq!(frame(&mut scene, 50), vec![]);
+
+        scene.get_component_mut::<Vec<Timer>>(entity).unwrap()[0].paused = false;
+        assert_eq!(frame(&mut scene, 10), vec![TimerFinished(entity.raw_id())]);
+    }
+
+    #[test]
+    fn changing_the_duration_mid_flight_applies_to_the_elapsed_time() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.timer(entity, Duration::from_millis(100));
+        frame(&mut scene, 40);
+
+        scene.get_component_mut::<Vec<Timer>>(entity).unwrap()[0].duration =
+            Duration::from_millis(50);
+
+        assert_eq!(frame(&mut scene, 10), vec![TimerFinished(entity.raw_id())]);
+    }
+
+    #[test]
+    fn entities_can_hold_several_timers() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.timer(entity1, Duration::from_millis(10));
+        scene.timer(entity1, Duration::from_millis(30));
+        scene.repeating_timer(entity2, Duration::from_millis(10));
+
+        assert_eq!(
+            frame(&mut scene, 10),
+            vec![
+                TimerFinished(entity1.raw_id()),
+                TimerFinished(entity2.raw_id())
+            ]
+        );
+        assert_eq!(
+            frame(&mut scene, 20),
+            vec![
+                TimerFinished(entity1.raw_id()),
+                TimerFinished(entity2.raw_id()),
+                TimerFinished(entity2.raw_id())
+            ]
+        );
+    }
+
+    #[test]
+    fn ticking_without_time_does_nothing() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.timer(entity, Duration::from_millis(10));
+        scene.tick_timers();
+
+        assert_eq!(scene.events::<TimerFinished>().count(), 0);
+    }
+}
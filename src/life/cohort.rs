@@ -0,0 +1,223 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use crate::life::{gizmos::PoolToolbox, ComponentPool, Scene};
+
+// Length of the shared prefix of every grouped pair, recorded by the last `regroup`.
+#[derive(Debug, Default)]
+pub struct Groups {
+    lengths: HashMap<(TypeId, TypeId), usize>,
+}
+
+impl Scene {
+    // Moves the entities enabled in both pools to the front of each packed array, in the same order.
+    // Assigning or removing components afterwards breaks the packing until the next call.
+    pub fn regroup<A: 'static, B: 'static>(&mut self) -> usize {
+        let length = match (self.try_get::<A>(), self.try_get::<B>()) {
+            (Some(_), Some(_)) => {
+                let (a, b) = self.get_two_mut::<A, B>();
+                pack(a, b)
+            }
+            _ => 0,
+        };
+
+        if !self.resource_exists::<Groups>() {
+            self.insert_resource(Groups::default());
+        }
+        self.resource_mut::<Groups>()
+            .lengths
+            .insert(group_key::<A, B>(), length);
+
+        length
+    }
+
+    pub fn group<A: 'static, B: 'static>(&self) -> impl Iterator<Item = (usize, &A, &B)> {
+        let length = self.group_length::<A, B>();
+        let (a, b) = match (self.try_get::<A>(), self.try_get::<B>()) {
+            (Some(a), Some(b)) if length > 0 => (a, b),
+            _ => return None.into_iter().flatten(),
+        };
+        debug_assert_eq!(
+            a.packed_array[..length],
+            b.packed_array[..length],
+            "Error: Group of {} and {} is stale, call regroup - Ignition",
+            std::any::type_name::<A>(),
+            std::any::type_name::<B>()
+        );
+
+        Some(
+            a.packed_array[..length]
+                .iter()
+                .copied()
+                .zip(&a.component_array[..length])
+                .zip(&b.component_array[..length])
+                .map(|((entity, a), b)| (entity, a, b)),
+        )
+        .into_iter()
+        .flatten()
+    }
+
+    pub fn group_mut<A: 'static, B: 'static>(
+        &mut self,
+    ) -> impl Iterator<Item = (usize, &mut A, &B)> {
+        let length = self.group_length::<A, B>();
+        let (a, b) = match (self.try_get::<A>(), self.try_get::<B>()) {
+            (Some(_), Some(_)) if length > 0 => self.get_two_mut::<A, B>(),
+            _ => return None.into_iter().flatten(),
+        };
+        debug_assert_eq!(
+            a.packed_array[..length],
+            b.packed_array[..length],
+            "Error: Group of {} and {} is stale, call regroup - Ignition",
+            std::any::type_name::<A>(),
+            std::any::type_name::<B>()
+        );
+
+        a.prolong_changed();
+        let changed = &mut a.changed;
+
+        Some(
+            a.packed_array[..length]
+                .iter()
+                .copied()
+                .zip(&mut a.component_array[..length])
+                .zip(&b.component_array[..length])
+                .map(move |((entity, a), b)| {
+                    changed[entity] = true;
+                    (entity, a, b)
+                }),
+        )
+        .into_iter()
+        .flatten()
+    }
+
+    // Clamped to both pools so a stale group never indexes past the enabled components.
+    pub fn group_length<A: 'static, B: 'static>(&self) -> usize {
+        let length = self
+            .try_resource::<Groups>()
+            .and_then(|groups| groups.lengths.get(&group_key::<A, B>()))
+            .copied()
+            .unwrap_or(0);
+
+        match (self.try_get::<A>(), self.try_get::<B>()) {
+            (Some(a), Some(b)) => length.min(a.num_components).min(b.num_components),
+            _ => 0,
+        }
+    }
+}
+
+fn group_key<A: 'static, B: 'static>() -> (TypeId, TypeId) {
+    (TypeId::of::<A>(), TypeId::of::<B>())
+}
+
+fn pack<A: 'static, B: 'static>(a: &mut ComponentPool<A>, b: &mut ComponentPool<B>) -> usize {
+    let mut length = 0;
+
+    for index in 0..a.num_components {
+        let entity = a.packed_array[index];
+        if !b.is_enabled(entity) {
+            continue;
+        }
+
+        a.swap_components(index, length);
+        let index_in_b = b.index_of(entity).unwrap();
+        b.swap_components(index_in_b, length);
+
+        length += 1;
+    }
+
+    length
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    struct Position(f32);
+
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    struct Velocity(f32);
+
+    fn interleaved() -> Scene {
+        let mut scene = Scene::new();
+
+        for index in 0..6 {
+            let entity = scene.entity();
+            if index % 2 == 0 {
+                scene.component(entity, Position(index as f32));
+            }
+            if index % 3 != 1 {
+                scene.component(entity, Velocity(index as f32 * 10.0));
+            }
+        }
+
+        scene
+    }
+
+    #[test]
+    fn regrouping_packs_shared_entities_at_the_front() {
+        let mut scene = interleaved();
+
+        let length = scene.regroup::<Position, Velocity>();
+
+        assert_eq!(length, 2);
+        assert_eq!(scene.get::<Position>().packed_array[..2], [0, 2]);
+        assert_eq!(scene.get::<Velocity>().packed_array[..2], [0, 2]);
+        assert_eq!(scene.get_component::<Position>(4), Some(&Position(4.0)));
+        assert_eq!(scene.get_component::<Velocity>(5), Some(&Velocity(50.0)));
+    }
+
+    #[test]
+    fn grouped_iteration_yields_matched_pairs() {
+        let mut scene = interleaved();
+        scene.regroup::<Position, Velocity>();
+
+        let pairs: Vec<(usize, Position, Velocity)> = scene
+            .group::<Position, Velocity>()
+            .map(|(entity, position, velocity)| (entity, *position, *velocity))
+            .collect();
+
+        assert_eq!(
+            pairs,
+            vec![
+                (0, Position(0.0), Velocity(0.0)),
+                (2, Position(2.0), Velocity(20.0))
+            ]
+        );
+    }
+
+    #[test]
+    fn grouped_iteration_can_mutate_the_first_pool() {
+        let mut scene = interleaved();
+        scene.regroup::<Position, Velocity>();
+
+        for (_, position, velocity) in scene.group_mut::<Position, Velocity>() {
+            position.0 += velocity.0;
+        }
+
+        assert_eq!(scene.get_component::<Position>(2), Some(&Position(22.0)));
+        assert_eq!(scene.get_component::<Position>(4), Some(&Position(4.0)));
+    }
+
+    #[test]
+    fn disabled_components_are_left_out_of_the_group() {
+        let mut scene = interleaved();
+        scene.disable::<Velocity>(0);
+
+        assert_eq!(scene.regroup::<Position, Velocity>(), 1);
+        assert_eq!(scene.get::<Velocity>().packed_array[0], 2);
+        assert!(!scene.get::<Velocity>().is_enabled(0));
+    }
+
+    #[test]
+    fn groups_are_empty_until_regrouped() {
+        let mut scene = interleaved();
+
+        assert_eq!(scene.group::<Position, Velocity>().count(), 0);
+        assert_eq!(scene.regroup::<Position, Velocity>(), 2);
+        assert_eq!(scene.group::<Position, Velocity>().count(), 2);
+        assert_eq!(scene.regroup::<Position, f64>(), 0);
+        assert_eq!(scene.group::<Position, f64>().count(), 0);
+    }
+}
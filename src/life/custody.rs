@@ -0,0 +1,156 @@
+use std::any::{type_name, TypeId};
+use std::cell::{Ref, RefCell, RefMut};
+
+use crate::life::{
+    abduction::missing_component_pool, catalogue::PoolRegistry, ComponentPool, ComponentPoolTrait,
+    Scene,
+};
+
+// Runtime-checked access to several pools at once, conflicting borrows panic with the component type.
+// The borrow state lives in the registry's cells, the view only keeps the Scene to itself meanwhile.
+pub struct Pools<'a> {
+    component_pools: &'a PoolRegistry,
+}
+
+impl Scene {
+    // Needs the Scene mutably, since Scene::get and friends hand out plain references to the pools
+    // that a borrow_mut on a shared Scene would alias.
+    pub fn pools(&mut self) -> Pools<'_> {
+        Pools {
+            component_pools: &self.component_pools,
+        }
+    }
+}
+
+impl<'a> Pools<'a> {
+    pub fn borrow<G: 'static>(&self) -> Ref<'_, ComponentPool<G>> {
+        let component_pool = self.cell::<G>().try_borrow().unwrap_or_else(|_| {
+            panic!(
+                "Error: Component pool for {} is already borrowed mutably - Ignition",
+                type_name::<G>()
+            )
+        });
+
+        Ref::map(component_pool, |component_pool| {
+            component_pool
+                .as_any()
+                .downcast_ref::<ComponentPool<G>>()
+                .unwrap()
+        })
+    }
+
+    pub fn borrow_mut<G: 'static>(&self) -> RefMut<'_, ComponentPool<G>> {
+        let component_pool = self.cell::<G>().try_borrow_mut().unwrap_or_else(|_| {
+            panic!(
+                "Error: Component pool for {} is already borrowed - Ignition",
+                type_name::<G>()
+            )
+        });
+
+        RefMut::map(component_pool, |component_pool| {
+            component_pool
+                .as_any_mut()
+                .downcast_mut::<ComponentPool<G>>()
+                .unwrap()
+        })
+    }
+
+    fn cell<G: 'static>(&self) -> &'a RefCell<Box<dyn ComponentPoolTrait>> {
+        self.component_pools
+            .cell(&TypeId::of::<G>())
+            .unwrap_or_else(|| missing_component_pool::<G>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    #[derive(Debug, PartialEq)]
+    struct Transform(f32);
+
+    #[derive(Debug, PartialEq)]
+    struct Shape(f32);
+
+    fn scene() -> Scene {
+        let mut scene = Scene::new();
+        for value in 0..3 {
+            let entity = scene.entity();
+            scene.component(entity, Transform(value as f32));
+            scene.component(entity, Shape(0.0));
+        }
+
+        scene
+    }
+
+    #[test]
+    fn one_pool_can_be_read_while_another_is_written() {
+        let mut scene = scene();
+
+        {
+            let pools = scene.pools();
+            let transforms = pools.borrow::<Transform>();
+            let mut shapes = pools.borrow_mut::<Shape>();
+
+            for (entity, transform) in transforms.iter_with_entities() {
+                shapes.get_mut(entity).0 = transform.0 * 2.0;
+            }
+        }
+
+        assert_eq!(scene.get_component::<Shape>(2), Some(&Shape(4.0)));
+    }
+
+    #[test]
+    fn borrows_end_with_their_guards() {
+        let mut scene = scene();
+
+        {
+            let pools = scene.pools();
+            pools.borrow_mut::<Shape>().get_mut(0).0 = 1.0;
+        }
+
+        assert_eq!(scene.get_component::<Shape>(0), Some(&Shape(1.0)));
+        assert_eq!(scene.pools().borrow_mut::<Shape>().get(0), &Shape(1.0));
+    }
+
+    #[test]
+    fn a_pool_can_be_read_twice() {
+        let mut scene = scene();
+        let pools = scene.pools();
+
+        let first = pools.borrow::<Transform>();
+        let second = pools.borrow::<Transform>();
+
+        assert_eq!(first.get(1), second.get(1));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Component pool for ignition::life::custody::tests::Shape is already borrowed"
+    )]
+    fn borrowing_a_pool_mutably_twice_panics() {
+        let mut scene = scene();
+        let pools = scene.pools();
+
+        let _shapes = pools.borrow_mut::<Shape>();
+        pools.borrow_mut::<Shape>();
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed mutably")]
+    fn reading_a_pool_being_written_panics() {
+        let mut scene = scene();
+        let pools = scene.pools();
+
+        let _shapes = pools.borrow_mut::<Shape>();
+        pools.borrow::<Shape>();
+    }
+
+    #[test]
+    #[should_panic(expected = "No component pool exists for f64")]
+    fn borrowing_a_missing_pool_panics() {
+        let mut scene = scene();
+
+        scene.pools().borrow::<f64>();
+    }
+}
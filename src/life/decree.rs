@@ -0,0 +1,144 @@
+use std::cell::RefCell;
+use std::mem;
+
+use crate::life::{
+    genesis::{bundle::ComponentBundle, entity::EntityKey},
+    Scene,
+};
+
+pub type Command = Box<dyn FnOnce(&mut Scene)>;
+pub type SpawnCommand = Command;
+
+pub struct Commands<'a> {
+    queue: &'a RefCell<Vec<Command>>,
+}
+
+impl Scene {
+    pub fn commands(&self) -> Commands<'_> {
+        Commands {
+            queue: &self.commands,
+        }
+    }
+
+    pub fn apply_commands(&mut self) {
+        let commands = mem::take(self.commands.get_mut());
+
+        for command in commands {
+            command(self);
+        }
+    }
+
+    pub fn pending_commands(&self) -> usize {
+        self.commands.borrow().len()
+    }
+}
+
+impl<'a> Commands<'a> {
+    pub fn spawn<B: 'static + ComponentBundle>(&self, bundle: B) {
+        self.queue.borrow_mut().push(spawn_command(bundle));
+    }
+
+    // Handles are resolved when the command is applied, so an entity deleted in between is left alone.
+    pub fn despawn(&self, entity: impl EntityKey + 'static) {
+        self.push(move |scene: &mut Scene| {
+            scene.delete(entity);
+        });
+    }
+
+    pub fn insert<G: 'static>(&self, entity: impl EntityKey + 'static, component: G) {
+        self.push(move |scene: &mut Scene| {
+            scene.component(entity, component);
+        });
+    }
+
+    pub fn push<F>(&self, command: F)
+    where
+        F: 'static + FnOnce(&mut Scene),
+    {
+        self.queue.borrow_mut().push(Box::new(command));
+    }
+}
+
+pub fn spawn_command<B: 'static + ComponentBundle>(bundle: B) -> SpawnCommand {
+    Box::new(move |scene: &mut Scene| {
+        let entity = scene.entity();
+        scene.components(entity, bundle);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    #[test]
+    fn despawning_while_iterating_a_pool_waits_for_apply() {
+        let mut scene = Scene::new();
+
+        for value in 0..3 {
+            let entity = scene.entity();
+            scene.component(entity, value);
+        }
+
+        for (entity, value) in scene.iter_with_entities::<i32>() {
+            if *value == 1 {
+                scene.commands().despawn(entity);
+            }
+        }
+
+        assert!(scene.has::<i32>(1));
+        assert_eq!(scene.pending_commands(), 1);
+
+        scene.apply_commands();
+
+        assert!(!scene.has::<i32>(1));
+        assert_eq!(scene.pending_commands(), 0);
+        assert_eq!(scene.entity_count(), 2);
+    }
+
+    #[test]
+    fn queued_commands_are_applied_in_order() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+
+        scene.commands().insert(entity, 1_i32);
+        scene.commands().insert(entity, 2_i32);
+        scene.commands().despawn(entity);
+        scene.commands().spawn((3_i32, 1.5_f32));
+
+        scene.apply_commands();
+
+        assert_eq!(scene.get_component::<i32>(entity), None);
+        assert_eq!(scene.get_component::<i32>(entity.raw_id()), Some(&3));
+        assert_eq!(scene.get_component::<f32>(entity.raw_id()), Some(&1.5));
+        assert_eq!(scene.entity_count(), 1);
+    }
+
+    #[test]
+    fn commands_for_an_entity_deleted_before_apply_are_dropped() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+
+        scene.commands().insert(entity, 1_i32);
+        scene.delete(entity);
+        let recycled = scene.entity();
+        scene.apply_commands();
+
+        assert_eq!(recycled.raw_id(), entity.raw_id());
+        assert!(!scene.component_pool_exists::<i32>());
+    }
+
+    #[test]
+    fn commands_queued_while_applying_wait_for_the_next_apply() {
+        let mut scene = Scene::new();
+
+        scene.commands().push(|scene: &mut Scene| {
+            scene.commands().spawn((7_u8,));
+        });
+
+        scene.apply_commands();
+        assert_eq!(scene.component_count::<u8>(), 0);
+
+        scene.apply_commands();
+        assert_eq!(scene.component_count::<u8>(), 1);
+    }
+}
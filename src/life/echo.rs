@@ -0,0 +1,156 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use crate::life::{
+    insignia::TagTable, mitosis::ComponentCloner, ComponentPool, ComponentPoolTrait, Scene,
+};
+
+pub type PoolCloner = fn(&dyn ComponentPoolTrait) -> Box<dyn ComponentPoolTrait>;
+
+#[derive(Clone, Copy)]
+pub struct Cloner {
+    pub pool: PoolCloner,
+    pub component: ComponentCloner,
+}
+
+pub struct SceneSnapshot {
+    next_id: usize,
+    free_list: Vec<usize>,
+    generations: Vec<u32>,
+    tags: TagTable,
+    component_pools: HashMap<TypeId, (Box<dyn ComponentPoolTrait>, Cloner)>,
+}
+
+impl Scene {
+    pub fn register_cloneable<G: 'static + Clone>(&mut self) {
+        let cloner = Cloner {
+            pool: |component_pool| {
+                Box::new(
+                    component_pool
+                        .as_any()
+                        .downcast_ref::<ComponentPool<G>>()
+                        .unwrap()
+                        .clone(),
+                )
+            },
+            component: |component_pool, source, destination| {
+                component_pool
+                    .as_any_mut()
+                    .downcast_mut::<ComponentPool<G>>()
+                    .unwrap()
+                    .clone_component_into(source, destination);
+            },
+        };
+
+        self.cloners.insert(TypeId::of::<G>(), cloner);
+    }
+
+    pub fn snapshot(&self) -> SceneSnapshot {
+        let mut component_pools = HashMap::new();
+
+        for (type_id, component_pool) in self.component_pools.iter() {
+            match self.cloners.get(type_id) {
+                Some(&cloner) => {
+                    component_pools.insert(*type_id, ((cloner.pool)(component_pool), cloner));
+                }
+                None => log::warn!(
+                    "Skipping component pool {:?} in snapshot, it was not registered as cloneable",
+                    type_id
+                ),
+            }
+        }
+
+        SceneSnapshot {
+            next_id: self.next_id,
+            free_list: self.free_list.clone(),
+            generations: self.generations.clone(),
+            tags: self.tags.clone(),
+            component_pools,
+        }
+    }
+
+    pub fn restore(&mut self, snapshot: &SceneSnapshot) {
+        self.next_id = snapshot.next_id;
+        self.free_list = snapshot.free_list.clone();
+        self.recount_live_entities();
+        self.generations = snapshot.generations.clone();
+        self.tags = snapshot.tags.clone();
+
+        self.component_pools
+            .retain(|type_id, _component_pool| !self.cloners.contains_key(type_id));
+
+        for (type_id, (component_pool, cloner)) in snapshot.component_pools.iter() {
+            self.component_pools
+                .insert(*type_id, (cloner.pool)(component_pool.as_ref()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    fn level() -> Scene {
+        let mut scene = Scene::new();
+        scene.register_cloneable::<i32>();
+        scene.register_cloneable::<String>();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, 32_i32);
+        scene.add_tag(entity1, "enemy");
+        let entity2 = scene.entity();
+        scene.component(entity2, 21_i32);
+        scene.component(entity2, String::from("ignition"));
+
+        scene
+    }
+
+    #[test]
+    fn restoring_a_snapshot_undoes_mutations_and_deletions() {
+        let mut scene = level();
+        let original = level();
+
+        let snapshot = scene.snapshot();
+
+        *scene.get_component_mut::<i32>(1).unwrap() = 7;
+        scene.delete(0);
+        let entity = scene.entity();
+        scene.component(entity, String::from("intruder"));
+
+        scene.restore(&snapshot);
+
+        assert_eq!(scene.next_id, original.next_id);
+        assert_eq!(scene.free_list, original.free_list);
+        assert_eq!(scene.generations, original.generations);
+        assert_eq!(scene.get::<i32>(), original.get::<i32>());
+        assert_eq!(scene.get::<String>(), original.get::<String>());
+        assert!(scene.has_tag(0, "enemy"));
+    }
+
+    #[test]
+    fn restoring_a_snapshot_twice_gives_the_same_scene() {
+        let mut scene = level();
+        let snapshot = scene.snapshot();
+
+        scene.delete(1);
+        scene.restore(&snapshot);
+        scene.delete(0);
+        scene.restore(&snapshot);
+
+        assert_eq!(scene.get_component::<i32>(0), Some(&32));
+        assert_eq!(scene.get_component::<i32>(1), Some(&21));
+        assert_eq!(scene.entity().raw_id(), 2);
+    }
+
+    #[test]
+    fn snapshots_skip_pools_that_are_not_cloneable() {
+        let mut scene = level();
+        scene.component(0, 1.5_f32);
+
+        let snapshot = scene.snapshot();
+        *scene.get_component_mut::<f32>(0).unwrap() = 3.0;
+        scene.restore(&snapshot);
+
+        assert_eq!(scene.get_component::<f32>(0), Some(&3.0));
+    }
+}
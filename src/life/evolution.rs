@@ -0,0 +1,85 @@
+use std::mem;
+
+use crate::life::Scene;
+
+pub type System = Box<dyn FnMut(&mut Scene)>;
+
+impl Scene {
+    pub fn add_system<F>(&mut self, system: F)
+    where
+        F: 'static + FnMut(&mut Scene),
+    {
+        self.systems.push(Box::new(system));
+    }
+
+    pub fn run_systems(&mut self) {
+        let mut systems = mem::take(&mut self.systems);
+
+        for system in systems.iter_mut() {
+            system(self);
+        }
+
+        systems.append(&mut self.systems);
+        self.systems = systems;
+    }
+
+    pub fn system_count(&self) -> usize {
+        self.systems.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    #[test]
+    fn running_systems_executes_them_in_insertion_order() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.component(entity, Vec::<u32>::new());
+
+        scene.add_system(move |scene: &mut Scene| {
+            scene.get_component_mut::<Vec<u32>>(entity).unwrap().push(1);
+        });
+        scene.add_system(move |scene: &mut Scene| {
+            scene.get_component_mut::<Vec<u32>>(entity).unwrap().push(2);
+        });
+
+        scene.run_systems();
+        scene.run_systems();
+
+        assert_eq!(
+            scene.get_component::<Vec<u32>>(entity),
+            Some(&vec![1, 2, 1, 2])
+        );
+    }
+
+    #[test]
+    fn systems_added_while_running_are_deferred_to_the_next_run() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.component(entity, 0_u32);
+
+        let mut added = false;
+        scene.add_system(move |scene: &mut Scene| {
+            *scene.get_component_mut::<u32>(entity).unwrap() += 1;
+
+            if !added {
+                added = true;
+                scene.add_system(move |scene: &mut Scene| {
+                    *scene.get_component_mut::<u32>(entity).unwrap() += 10;
+                });
+            }
+        });
+
+        scene.run_systems();
+        assert_eq!(scene.get_component::<u32>(entity), Some(&1));
+        assert_eq!(scene.system_count(), 2);
+
+        scene.run_systems();
+        assert_eq!(scene.get_component::<u32>(entity), Some(&12));
+        assert_eq!(scene.system_count(), 2);
+    }
+}
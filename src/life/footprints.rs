@@ -0,0 +1,250 @@
+use std::any::TypeId;
+
+use crate::life::{ComponentPool, Scene};
+
+impl Scene {
+    pub fn changed<G: 'static>(&self) -> Vec<usize> {
+        self.try_get::<G>()
+            .map_or_else(Vec::new, |component_pool| component_pool.changed())
+    }
+
+    // Drained changes are kept for the renderer until `forget_drained`, since it runs after the systems
+    // and the loop closure that usually drain them.
+    pub fn drain_changed<G: 'static>(&mut self) -> Vec<usize> {
+        let changed = self
+            .try_get_mut::<G>()
+            .map_or_else(Vec::new, |component_pool| component_pool.drain_changed());
+
+        if let Some(&last) = changed.iter().max() {
+            let drained = self.drained.entry(TypeId::of::<G>()).or_default();
+            if last >= drained.len() {
+                drained.resize(last + 1, false);
+            }
+            for &entity in &changed {
+                drained[entity] = true;
+            }
+        }
+
+        changed
+    }
+
+    pub fn changed_since_render<G: 'static>(&self, entity: usize) -> bool {
+        let changed = self
+            .try_get::<G>()
+            .is_some_and(|component_pool| component_pool.is_changed(entity));
+        let drained = self
+            .drained
+            .get(&TypeId::of::<G>())
+            .and_then(|drained| drained.get(entity))
+            .copied()
+            .unwrap_or(false);
+
+        changed || drained
+    }
+
+    pub fn forget_drained(&mut self) {
+        self.drained.clear();
+    }
+}
+
+impl<G> ComponentPool<G> {
+    pub fn mark_changed(&mut self, entity: usize) {
+        if entity >= self.changed.len() {
+            self.changed.resize(entity + 1, false);
+        }
+
+        self.changed[entity] = true;
+    }
+
+    // Sizes the flags for every entity the pool can hold, so iterators can mark without growing them.
+    pub fn prolong_changed(&mut self) {
+        if self.changed.len() < self.sparse_array.len() {
+            self.changed.resize(self.sparse_array.len(), false);
+        }
+    }
+
+    // For engine passes that only refresh GPU state, which users should not see as a change.
+    pub fn iter_with_entities_unmarked_mut(&mut self) -> impl Iterator<Item = (usize, &mut G)> {
+        let (left, _right) = self.component_array.split_at_mut(self.num_components);
+
+        self.packed_array.iter().copied().zip(left.iter_mut())
+    }
+
+    pub fn unmark_changed(&mut self, entity: usize) {
+        if let Some(changed) = self.changed.get_mut(entity) {
+            *changed = false;
+        }
+    }
+
+    pub fn is_changed(&self, entity: usize) -> bool {
+        self.changed.get(entity).copied().unwrap_or(false)
+    }
+
+    pub fn changed(&self) -> Vec<usize> {
+        self.changed
+            .iter()
+            .enumerate()
+            .filter(|(_entity, &changed)| changed)
+            .map(|(entity, _changed)| entity)
+            .collect()
+    }
+
+    pub fn drain_changed(&mut self) -> Vec<usize> {
+        let changed = self.changed();
+        self.changed.clear();
+
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    fn scene_with_three_components() -> Scene {
+        let mut scene = Scene::new();
+
+        for value in 0..3 {
+            let entity = scene.entity();
+            scene.component(entity, value);
+        }
+        scene.drain_changed::<i32>();
+
+        scene
+    }
+
+    #[test]
+    fn mutating_one_component_reports_only_its_entity() {
+        let mut scene = scene_with_three_components();
+
+        *scene.get_component_mut::<i32>(1).unwrap() += 10;
+
+        assert_eq!(scene.changed::<i32>(), vec![1]);
+    }
+
+    #[test]
+    fn assigning_components_marks_them_as_changed() {
+        let mut scene = scene_with_three_components();
+
+        scene.component(2, 7_i32);
+        let entity = scene.entity();
+        scene.component(entity, 8_i32);
+
+        assert_eq!(scene.changed::<i32>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn draining_changes_resets_them_until_the_next_mutation() {
+        let mut scene = scene_with_three_components();
+
+        *scene.get_component_mut::<i32>(0).unwrap() += 1;
+
+        assert_eq!(scene.drain_changed::<i32>(), vec![0]);
+        assert_eq!(scene.changed::<i32>(), Vec::<usize>::new());
+
+        *scene.get_component_mut::<i32>(2).unwrap() += 1;
+
+        assert_eq!(scene.changed::<i32>(), vec![2]);
+    }
+
+    #[test]
+    fn mutable_iteration_marks_every_yielded_component() {
+        let mut scene = scene_with_three_components();
+        scene.disable::<i32>(1);
+
+        for value in scene.iter_mut::<i32>() {
+            *value += 1;
+        }
+
+        assert_eq!(scene.changed::<i32>(), vec![0, 2]);
+    }
+
+    #[test]
+    fn mutable_iteration_marks_only_what_was_consumed() {
+        let mut scene = scene_with_three_components();
+
+        scene.iter_with_entities_mut::<i32>().next();
+
+        assert_eq!(scene.changed::<i32>(), vec![0]);
+    }
+
+    #[test]
+    fn mutable_queries_mark_the_mutated_components() {
+        let mut scene = scene_with_three_components();
+        scene.component(1, 1.5_f32);
+        scene.drain_changed::<f32>();
+
+        for (_entity, value, _float) in scene.get_pair_mut::<i32, f32>() {
+            *value += 1;
+        }
+
+        assert_eq!(scene.changed::<i32>(), vec![1]);
+        assert_eq!(scene.changed::<f32>(), Vec::<usize>::new());
+
+        scene
+            .query_mut::<(f32,)>()
+            .for_each(|(_entity, float)| *float = 0.5);
+
+        assert_eq!(scene.changed::<f32>(), vec![1]);
+    }
+
+    #[test]
+    fn mutable_groups_mark_the_first_pool() {
+        let mut scene = scene_with_three_components();
+        scene.component(2, 'c');
+        scene.regroup::<i32, char>();
+        scene.drain_changed::<char>();
+
+        for (_entity, value, _char) in scene.group_mut::<i32, char>() {
+            *value += 1;
+        }
+
+        assert_eq!(scene.changed::<i32>(), vec![2]);
+        assert_eq!(scene.changed::<char>(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn drained_changes_stay_visible_to_the_renderer_until_forgotten() {
+        let mut scene = scene_with_three_components();
+        scene.forget_drained();
+
+        *scene.get_component_mut::<i32>(1).unwrap() += 1;
+        scene.drain_changed::<i32>();
+
+        assert!(scene.changed_since_render::<i32>(1));
+        assert!(!scene.changed_since_render::<i32>(0));
+
+        scene.forget_drained();
+
+        assert!(!scene.changed_since_render::<i32>(1));
+    }
+
+    #[test]
+    fn unmarked_iteration_reports_nothing() {
+        let mut scene = scene_with_three_components();
+
+        for (_entity, value) in scene.get_mut::<i32>().iter_with_entities_unmarked_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(scene.changed::<i32>(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn removing_a_component_forgets_its_change() {
+        let mut scene = scene_with_three_components();
+
+        *scene.get_component_mut::<i32>(1).unwrap() += 1;
+        scene.remove_component::<i32>(1);
+
+        assert_eq!(scene.changed::<i32>(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn asking_for_changes_of_a_missing_pool_returns_nothing() {
+        let mut scene = Scene::new();
+
+        assert_eq!(scene.changed::<i32>(), Vec::<usize>::new());
+        assert_eq!(scene.drain_changed::<i32>(), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,405 @@
+use std::any::TypeId;
+use std::io::{Read, Write};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::life::{
+    catalogue::PoolRegistry, insignia::TagTable, ComponentPool, ComponentPoolTrait, Scene,
+};
+
+pub struct PoolSerializer {
+    pub name: &'static str,
+
+    to_bytes: fn(&dyn ComponentPoolTrait) -> Vec<u8>,
+    from_bytes: fn(&[u8]) -> bincode::Result<Box<dyn ComponentPoolTrait>>,
+}
+
+type SceneFossil = (
+    usize,
+    Vec<usize>,
+    Vec<u32>,
+    Vec<(String, Vec<usize>)>,
+    Vec<(String, Vec<u8>)>,
+);
+
+impl Scene {
+    pub fn register_serializable<G: 'static + Serialize + DeserializeOwned>(
+        &mut self,
+        name: &'static str,
+    ) {
+        self.serializers.insert(
+            TypeId::of::<G>(),
+            PoolSerializer {
+                name,
+
+                to_bytes: |component_pool| {
+                    component_pool
+                        .as_any()
+                        .downcast_ref::<ComponentPool<G>>()
+                        .unwrap()
+                        .to_bytes()
+                },
+                from_bytes: ComponentPool::<G>::from_bytes,
+            },
+        );
+    }
+
+    pub fn serialize_all(&self) -> Vec<u8> {
+        let mut component_pools: Vec<(&str, Vec<u8>)> = self
+            .serializers
+            .iter()
+            .filter_map(|(type_id, serializer)| {
+                let component_pool = self.component_pools.get(type_id)?;
+                Some((serializer.name, (serializer.to_bytes)(component_pool)))
+            })
+            .collect();
+        component_pools.sort_by_key(|(name, _bytes)| *name);
+
+        let tags: Vec<(&str, &[usize])> = self.tags.tagged().collect();
+
+        bincode::serialize(&(
+            self.next_id,
+            &self.free_list,
+            &self.generations,
+            tags,
+            component_pools,
+        ))
+        .unwrap()
+    }
+
+    pub fn save_to_writer<W: Write>(&self, mut writer: W) -> bincode::Result<()> {
+        writer.write_all(&self.serialize_all())?;
+
+        Ok(())
+    }
+
+    pub fn load_from_reader<R: Read>(&mut self, mut reader: R) -> bincode::Result<()> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        self.deserialize_all(&bytes)
+    }
+
+    pub fn deserialize_all(&mut self, bytes: &[u8]) -> bincode::Result<()> {
+        let (next_id, free_list, generations, tagged, fossils): SceneFossil =
+            bincode::deserialize(bytes)?;
+        if free_list.iter().any(|&entity| entity >= next_id) {
+            return Err(corrupted(
+                "the free list holds ids that were never handed out",
+            ));
+        }
+
+        let mut component_pools = PoolRegistry::new();
+        for (name, bytes) in fossils {
+            let (type_id, serializer) = self
+                .serializers
+                .iter()
+                .find(|(_type_id, serializer)| serializer.name == name)
+                .ok_or_else(|| {
+                    bincode::ErrorKind::Custom(format!(
+                        "Error: No serializable component pool registered for {} - Ignition",
+                        name
+                    ))
+                })?;
+
+            component_pools.insert(*type_id, (serializer.from_bytes)(&bytes)?);
+        }
+
+        let mut tags = TagTable::new();
+        for (tag, entities) in tagged {
+            tags.intern(&tag);
+
+            for entity in entities {
+                tags.pool_mut(&tag).unwrap().assign_component(entity, ());
+            }
+        }
+
+        self.next_id = next_id;
+        self.free_list = free_list;
+        self.recount_live_entities();
+        self.generations = generations;
+        self.tags = tags;
+        self.component_pools = component_pools;
+
+        Ok(())
+    }
+}
+
+pub trait SerializablePool {
+    fn to_bytes(&self) -> Vec<u8>;
+    fn from_bytes(bytes: &[u8]) -> bincode::Result<Box<dyn ComponentPoolTrait>>
+    where
+        Self: Sized;
+}
+
+impl<G: 'static + Serialize + DeserializeOwned> SerializablePool for ComponentPool<G> {
+    fn to_bytes(&self) -> Vec<u8> {
+        bincode::serialize(&(
+            self.num_components,
+            &self.sparse_array,
+            &self.packed_array,
+            &self.component_array,
+        ))
+        .unwrap()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> bincode::Result<Box<dyn ComponentPoolTrait>> {
+        let (num_components, sparse_array, packed_array, component_array): (
+            usize,
+            Vec<Option<u32>>,
+            Vec<usize>,
+            Vec<G>,
+        ) = bincode::deserialize(bytes)?;
+        check_layout(
+            num_components,
+            &sparse_array,
+            &packed_array,
+            component_array.len(),
+        )?;
+
+        Ok(Box::new(ComponentPool {
+            num_components,
+
+            sparse_array,
+            packed_array,
+            component_array,
+
+            changed: Vec::new(),
+        }))
+    }
+}
+
+// Every packed entity must point back at its own slot, and nothing else may be in the sparse array.
+fn check_layout(
+    num_components: usize,
+    sparse_array: &[Option<u32>],
+    packed_array: &[usize],
+    num_stored: usize,
+) -> bincode::Result<()> {
+    if packed_array.len() != num_stored {
+        return Err(corrupted("packed entities and components differ in number"));
+    }
+    if num_components > num_stored {
+        return Err(corrupted("more components are enabled than stored"));
+    }
+
+    for (index, &entity) in packed_array.iter().enumerate() {
+        if sparse_array.get(entity).copied().flatten() != Some(index as u32) {
+            return Err(corrupted(
+                "a packed entity is missing from the sparse array",
+            ));
+        }
+    }
+    if sparse_array.iter().flatten().count() != packed_array.len() {
+        return Err(corrupted(
+            "the sparse array points at components that are not packed",
+        ));
+    }
+
+    Ok(())
+}
+
+fn corrupted(reason: &str) -> bincode::Error {
+    Box::new(bincode::ErrorKind::Custom(format!(
+        "Error: Corrupted save, {} - Ignition",
+        reason
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::{fossil::SerializablePool, ComponentPool, Scene};
+
+    fn saved_scene() -> Scene {
+        let mut scene = Scene::new();
+        scene.register_serializable::<i32>("i32");
+        scene.register_serializable::<String>("name");
+
+        let entity1 = scene.entity();
+        scene.component(entity1, 32_i32);
+        let entity2 = scene.entity();
+        scene.component(entity2, 21_i32);
+        scene.component(entity2, String::from("ignition"));
+        let entity3 = scene.entity();
+        scene.component(entity3, 17_i32);
+
+        scene.disable::<i32>(entity1);
+        scene.delete(entity3);
+
+        scene
+    }
+
+    #[test]
+    fn serializing_a_pool_round_trips_its_layout() {
+        let mut pool = ComponentPool::new_with_entity(1, 32_i32);
+        pool.assign_component(3, 21_i32);
+
+        let restored = ComponentPool::<i32>::from_bytes(&pool.to_bytes()).unwrap();
+
+        assert_eq!(
+            restored.as_any().downcast_ref::<ComponentPool<i32>>(),
+            Some(&ComponentPool {
+                num_components: 2,
+
+                sparse_array: vec![None, Some(0), None, Some(1)],
+                packed_array: vec![1, 3],
+                component_array: vec![32, 21],
+
+                changed: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn deserializing_a_saved_scene_restores_entities_and_components() {
+        let bytes = saved_scene().serialize_all();
+
+        let mut scene = Scene::new();
+        scene.register_serializable::<i32>("i32");
+        scene.register_serializable::<String>("name");
+        scene.deserialize_all(&bytes).unwrap();
+
+        assert_eq!(scene.next_id, 3);
+        assert_eq!(scene.free_list, vec![2]);
+        assert_eq!(scene.generations, vec![0, 0, 1]);
+        assert_eq!(scene.get::<i32>().iter().collect::<Vec<_>>(), vec![&21]);
+        assert_eq!(scene.get_component::<i32>(0), Some(&32));
+        assert_eq!(
+            scene.get_component::<String>(1),
+            Some(&String::from("ignition"))
+        );
+        assert_eq!(scene.entity().raw_id(), 2);
+    }
+
+    #[test]
+    fn serializing_skips_unregistered_component_pools() {
+        let mut scene = saved_scene();
+        scene.component(0, 1.5_f32);
+
+        let bytes = scene.serialize_all();
+        scene.deserialize_all(&bytes).unwrap();
+
+        assert!(!scene.component_pool_exists::<f32>());
+        assert!(scene.component_pool_exists::<i32>());
+    }
+
+    #[test]
+    fn deserializing_an_unregistered_pool_fails_without_touching_the_scene() {
+        let bytes = saved_scene().serialize_all();
+
+        let mut scene = Scene::new();
+        scene.register_serializable::<i32>("i32");
+        let entity = scene.entity();
+        scene.component(entity, 7_i32);
+
+        assert!(scene.deserialize_all(&bytes).is_err());
+        assert_eq!(scene.get_component::<i32>(entity), Some(&7));
+        assert_eq!(scene.next_id, 1);
+        assert_eq!(scene.free_list, Vec::<usize>::new());
+    }
+
+    #[test]
+    fn deserializing_a_saved_scene_restores_tags() {
+        let mut scene = saved_scene();
+        scene.add_tag(0, "enemy");
+        scene.add_tag(1, "enemy");
+        scene.add_tag(1, "checkpoint:3");
+
+        let bytes = scene.serialize_all();
+        let mut restored = Scene::new();
+        restored.register_serializable::<i32>("i32");
+        restored.register_serializable::<String>("name");
+        restored.deserialize_all(&bytes).unwrap();
+
+        assert_eq!(
+            restored.with_tag("enemy").collect::<Vec<usize>>(),
+            vec![0, 1]
+        );
+        assert!(restored.has_tag(1, "checkpoint:3"));
+        assert_eq!(
+            restored.tag_id("checkpoint:3"),
+            scene.tag_id("checkpoint:3")
+        );
+    }
+
+    #[test]
+    fn saving_and_loading_through_io_round_trips_the_scene() {
+        let mut scene = saved_scene();
+        scene.drain_changed::<i32>();
+        scene.drain_changed::<String>();
+
+        let mut file = Vec::new();
+        scene.save_to_writer(&mut file).unwrap();
+
+        let mut restored = Scene::new();
+        restored.register_serializable::<i32>("i32");
+        restored.register_serializable::<String>("name");
+        restored.load_from_reader(file.as_slice()).unwrap();
+
+        assert_eq!(restored.next_id, scene.next_id);
+        assert_eq!(restored.free_list, scene.free_list);
+        assert_eq!(restored.generations, scene.generations);
+        assert_eq!(restored.get::<i32>(), scene.get::<i32>());
+        assert_eq!(restored.get::<String>(), scene.get::<String>());
+    }
+
+    #[test]
+    fn loading_matches_pools_by_registered_name() {
+        let bytes = saved_scene().serialize_all();
+
+        let mut scene = Scene::new();
+        scene.register_serializable::<i32>("i32");
+        scene.register_serializable::<String>("label");
+
+        assert!(scene.deserialize_all(&bytes).is_err());
+    }
+
+    fn corrupted_pool(
+        num_components: usize,
+        sparse_array: Vec<Option<u32>>,
+        packed_array: Vec<usize>,
+        component_array: Vec<i32>,
+    ) -> Vec<u8> {
+        bincode::serialize(&(num_components, sparse_array, packed_array, component_array)).unwrap()
+    }
+
+    #[test]
+    fn loading_a_corrupted_pool_fails() {
+        let corrupted = [
+            corrupted_pool(3, vec![Some(0), Some(1)], vec![0, 1], vec![32, 21]),
+            corrupted_pool(2, vec![Some(0), Some(1)], vec![0, 1], vec![32]),
+            corrupted_pool(2, vec![Some(1), Some(0)], vec![0, 1], vec![32, 21]),
+            corrupted_pool(1, vec![Some(0), Some(0)], vec![0], vec![32]),
+            corrupted_pool(1, vec![Some(0)], vec![5], vec![32]),
+        ];
+
+        for bytes in corrupted {
+            match ComponentPool::<i32>::from_bytes(&bytes) {
+                Err(error) => assert!(error.to_string().contains("Corrupted save"), "{}", error),
+                Ok(_) => panic!("Error: Corrupted pool was loaded - Ignition"),
+            }
+        }
+    }
+
+    #[test]
+    fn loading_a_truncated_pool_fails() {
+        let mut pool = ComponentPool::new_with_entity(1, 32_i32);
+        pool.assign_component(3, 21_i32);
+        let bytes = pool.to_bytes();
+
+        assert!(ComponentPool::<i32>::from_bytes(&bytes[..bytes.len() - 2]).is_err());
+    }
+
+    #[test]
+    fn loading_a_free_list_beyond_the_handed_out_ids_fails() {
+        let mut scene = saved_scene();
+        scene.free_list.push(10);
+        let bytes = scene.serialize_all();
+
+        let mut loaded = Scene::new();
+        loaded.register_serializable::<i32>("i32");
+        loaded.register_serializable::<String>("name");
+
+        assert!(loaded.deserialize_all(&bytes).is_err());
+    }
+}
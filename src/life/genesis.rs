@@ -1,37 +1,115 @@
+use std::cell::RefCell;
 use std::collections::HashMap;
 
+pub mod batch;
+pub mod bundle;
 pub mod component;
 pub mod entity;
+pub mod spawn;
 
-use crate::life::{ComponentPool, Scene};
+use crate::life::{catalogue::PoolRegistry, insignia::TagTable, ComponentPool, Scene};
 
 impl Scene {
     pub fn new() -> Self {
         Self {
-            available_entities: vec![0],
-            component_pools: HashMap::new(),
+            next_id: 0,
+            free_list: Vec::new(),
+            alive: Vec::new(),
+            generations: Vec::new(),
+            component_pools: PoolRegistry::new(),
+            resources: HashMap::new(),
+            events: HashMap::new(),
+            tags: TagTable::new(),
+            systems: Vec::new(),
+            shutdown_systems: Vec::new(),
+            commands: RefCell::new(Vec::new()),
+            serializers: HashMap::new(),
+            cloners: HashMap::new(),
+            inserters: HashMap::new(),
+            hooks: HashMap::new(),
+            drained: HashMap::new(),
+
+            render_below: false,
+            update_below: false,
+
+            capacity: 0,
         }
     }
+
+    // Pools created later size their sparse arrays for `capacity` entities up front. Their dense arrays
+    // grow with the components actually assigned, `reserve` sizes them for one type.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut scene = Self::new();
+        scene.alive.reserve(capacity);
+        scene.generations.reserve(capacity);
+        scene.capacity = capacity;
+
+        scene
+    }
+}
+
+impl Default for Scene {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<G> ComponentPool<G> {
+    pub fn new() -> Self {
+        Self {
+            num_components: 0,
+
+            sparse_array: Vec::new(),
+            packed_array: Vec::new(),
+            component_array: Vec::new(),
+
+            changed: Vec::new(),
+        }
+    }
+
     pub fn new_with_entity(entity: usize, component: G) -> Self {
         let mut sparse_array = Vec::with_capacity(entity + 1);
         Self::add_entity_to_sparse_array(entity, 0, &mut sparse_array);
 
-        Self {
+        let mut component_pool = Self {
             num_components: 1,
 
             sparse_array,
             packed_array: vec![entity],
             component_array: vec![component],
+
+            changed: Vec::new(),
+        };
+        component_pool.mark_changed(entity);
+
+        component_pool
+    }
+}
+
+impl<G> ComponentPool<G> {
+    // `entities` sizes the arrays indexed by entity, `components` the packed ones.
+    pub fn with_capacity(entities: usize, components: usize) -> Self {
+        Self {
+            num_components: 0,
+
+            sparse_array: Vec::with_capacity(entities),
+            packed_array: Vec::with_capacity(components),
+            component_array: Vec::with_capacity(components),
+
+            changed: Vec::with_capacity(entities),
         }
     }
 }
 
+impl<G> Default for ComponentPool<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::life::ComponentPool;
+    use crate::life::{ComponentPool, Scene};
 
     #[test]
     fn component_pool_creation_works() {
@@ -42,10 +120,43 @@ mod tests {
             ComponentPool {
                 num_components: 1,
 
-                sparse_array: vec![-1, -1, -1, 0],
+                sparse_array: vec![None, None, None, Some(0)],
                 packed_array: vec![3],
                 component_array: vec![32],
+
+                changed: vec![false, false, false, true],
             },
         );
     }
+
+    #[test]
+    fn scenes_with_capacity_preallocate_only_the_sparse_arrays() {
+        let mut scene = Scene::with_capacity(10_000);
+
+        let entity = scene.entity();
+        scene.component(entity, 32_i32);
+        scene.component(entity, 1.5_f64);
+
+        let pool = scene.get::<i32>();
+        assert!(pool.sparse_array.capacity() >= 10_000);
+        assert!(pool.component_array.capacity() < 10_000);
+
+        let pool = scene.get::<f64>();
+        assert!(pool.sparse_array.capacity() >= 10_000);
+        assert!(pool.packed_array.capacity() < 10_000);
+    }
+
+    #[test]
+    fn scenes_with_capacity_lay_out_pools_like_any_other() {
+        let mut preallocated = Scene::with_capacity(100);
+        let mut scene = Scene::new();
+
+        for scene in [&mut preallocated, &mut scene] {
+            scene.entity();
+            let entity = scene.entity();
+            scene.component(entity, 32_i32);
+        }
+
+        assert_eq!(preallocated.get::<i32>(), scene.get::<i32>());
+    }
 }
@@ -0,0 +1,223 @@
+use crate::life::{
+    genesis::entity::EntityKey, ghost::ComponentToggler, ComponentPool, Entity, Scene,
+};
+
+impl Scene {
+    pub fn spawn_batch<G: 'static>(
+        &mut self,
+        components: impl IntoIterator<Item = G>,
+    ) -> Vec<Entity> {
+        let components: Vec<G> = components.into_iter().collect();
+        let entities: Vec<Entity> = components.iter().map(|_| self.entity()).collect();
+
+        if !self.component_pool_exists::<G>() {
+            self.new_empty_component_pool::<G>();
+        }
+        self.get_mut::<G>()
+            .extend(entities.iter().map(Entity::raw_id).zip(components));
+        for entity in &entities {
+            self.run_insert_hooks::<G>(entity.raw_id());
+        }
+
+        entities
+    }
+
+    // Makes room for `additional` more components, assumed to belong to entities that are not spawned yet.
+    pub fn reserve<G: 'static>(&mut self, additional: usize) {
+        if !self.component_pool_exists::<G>() {
+            self.new_empty_component_pool::<G>();
+        }
+
+        let entities = self.next_id + additional;
+        let component_pool = self.get_mut::<G>();
+        component_pool.reserve(additional);
+        component_pool
+            .sparse_array
+            .reserve(entities.saturating_sub(component_pool.sparse_array.len()));
+    }
+
+    pub fn despawn_batch<E: EntityKey>(&mut self, entities: &[E]) {
+        for &entity in entities {
+            self.delete(entity);
+        }
+    }
+}
+
+impl<G> ComponentPool<G> {
+    pub fn reserve(&mut self, additional: usize) {
+        self.packed_array.reserve(additional);
+        self.component_array.reserve(additional);
+    }
+
+    // Grows the sparse array once for the largest entity, then appends like `assign_component`.
+    pub fn extend<I: IntoIterator<Item = (usize, G)>>(&mut self, components: I) {
+        let components: Vec<(usize, G)> = components.into_iter().collect();
+
+        if let Some(&(last, _)) = components.iter().max_by_key(|(entity, _)| *entity) {
+            Self::prolong_sparse_array(last, &mut self.sparse_array);
+        }
+        self.reserve(components.len());
+
+        for (entity, component) in components {
+            self.assign_component(entity, component);
+        }
+    }
+}
+
+impl<G: 'static> ComponentPool<G> {
+    // Components from `other` overwrite ones already assigned to the same entity and keep their enabled state.
+    pub fn merge(&mut self, other: ComponentPool<G>) {
+        let disabled: Vec<usize> = other.packed_array[other.num_components..].to_vec();
+
+        for entity in &other.packed_array[..other.num_components] {
+            if self.has_component(*entity) && !self.is_enabled(*entity) {
+                self.enable_entity(*entity);
+            }
+        }
+        self.extend(other.packed_array.into_iter().zip(other.component_array));
+        for entity in disabled {
+            if self.is_enabled(entity) {
+                self.disable_entity(entity);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::{ghost::ComponentToggler, ComponentPool, Scene};
+
+    #[test]
+    fn spawning_a_batch_creates_one_entity_per_component() {
+        let mut scene = Scene::new();
+
+        let entities = scene.spawn_batch(0..1000);
+
+        assert_eq!(entities.len(), 1000);
+        assert_eq!(scene.entity_count(), 1000);
+        assert_eq!(scene.component_count::<i32>(), 1000);
+        assert_eq!(scene.get_component::<i32>(entities[999]), Some(&999));
+    }
+
+    #[test]
+    fn batches_lay_out_pools_like_individual_spawns() {
+        let mut batched = Scene::new();
+        let mut individual = Scene::new();
+
+        for scene in [&mut batched, &mut individual] {
+            let entity = scene.entity();
+            scene.component(entity, -1_i32);
+            let entity = scene.entity();
+            scene.delete(entity);
+        }
+
+        batched.spawn_batch(0..1000);
+        for value in 0..1000 {
+            let entity = individual.entity();
+            individual.component(entity, value);
+        }
+
+        assert_eq!(batched.get::<i32>(), individual.get::<i32>());
+        assert_eq!(batched.next_id, individual.next_id);
+        assert_eq!(batched.free_list, individual.free_list);
+    }
+
+    #[test]
+    fn despawning_a_batch_frees_every_entity() {
+        let mut scene = Scene::new();
+
+        let entities = scene.spawn_batch(vec![1.5_f32; 10]);
+        scene.despawn_batch(&entities[2..8]);
+
+        assert_eq!(scene.entity_count(), 4);
+        assert_eq!(scene.component_count::<f32>(), 4);
+        assert!(scene.has::<f32>(entities[1]));
+        assert!(!scene.has::<f32>(entities[2]));
+    }
+
+    #[test]
+    fn reserving_creates_an_empty_pool() {
+        let mut scene = Scene::new();
+
+        scene.reserve::<i32>(1000);
+
+        assert!(scene.component_pool_exists::<i32>());
+        assert_eq!(scene.get::<i32>(), &ComponentPool::new());
+        assert!(scene.get::<i32>().component_array.capacity() >= 1000);
+        assert!(scene.get::<i32>().sparse_array.capacity() >= 1000);
+    }
+
+    #[test]
+    fn reserving_keeps_existing_components() {
+        let mut scene = Scene::new();
+        let entities = scene.spawn_batch(vec!['a', 'b', 'c']);
+        scene.disable::<char>(entities[1]);
+        let before = scene.get::<char>().clone();
+
+        scene.reserve::<char>(500);
+
+        assert_eq!(scene.get::<char>(), &before);
+        assert!(scene.get::<char>().packed_array.capacity() >= 503);
+        assert!(scene.get::<char>().sparse_array.capacity() >= 503);
+    }
+
+    #[test]
+    fn pools_with_capacity_size_entities_and_components_separately() {
+        let pool = ComponentPool::<u8>::with_capacity(5000, 10);
+
+        assert_eq!(pool, ComponentPool::new());
+        assert!(pool.sparse_array.capacity() >= 5000);
+        assert!(pool.component_array.capacity() >= 10);
+    }
+
+    #[test]
+    fn extending_a_pool_grows_the_sparse_array_once() {
+        let mut pool = ComponentPool::new();
+
+        pool.extend(vec![(3, 'c'), (1, 'a')]);
+
+        assert_eq!(pool.sparse_array, vec![None, Some(1), None, Some(0)]);
+        assert_eq!(pool.packed_array, vec![3, 1]);
+        assert_eq!(pool.component_array, vec!['c', 'a']);
+        assert_eq!(pool.num_components, 2);
+    }
+
+    #[test]
+    fn merging_pools_overwrites_colliding_entities() {
+        let mut pool = ComponentPool::new();
+        pool.extend(vec![(0, 'a'), (2, 'b')]);
+        let mut other = ComponentPool::new();
+        other.extend(vec![(5, 'x'), (2, 'y')]);
+
+        pool.merge(other);
+
+        assert_eq!(
+            pool.sparse_array,
+            vec![Some(0), None, Some(1), None, None, Some(2)]
+        );
+        assert_eq!(pool.packed_array, vec![0, 2, 5]);
+        assert_eq!(pool.component_array, vec!['a', 'y', 'x']);
+        assert_eq!(pool.num_components, 3);
+    }
+
+    #[test]
+    fn merging_pools_keeps_the_enabled_state_of_the_merged_components() {
+        let mut scene = Scene::new();
+        scene.spawn_batch(vec!['a', 'b', 'c']);
+        scene.disable::<char>(0);
+        scene.disable::<char>(1);
+        let mut other = ComponentPool::new();
+        other.extend(vec![(1, 'y'), (3, 'z'), (4, 'w')]);
+        other.disable_entity(4);
+
+        scene.get_mut::<char>().merge(other);
+
+        let pool = scene.get::<char>();
+        assert_eq!(pool.enabled_entities().len(), 3);
+        assert!(!pool.is_enabled(0));
+        assert!(!pool.is_enabled(4));
+        assert_eq!(pool.get_enabled(1), Some(&'y'));
+        assert_eq!(pool.get_enabled(3), Some(&'z'));
+        assert_eq!(pool.try_get(4), Some(&'w'));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::life::{genesis::entity::EntityKey, Scene};
+
+pub trait ComponentBundle {
+    fn insert(self, scene: &mut Scene, entity: usize);
+}
+
+impl Scene {
+    pub fn components<B: ComponentBundle>(&mut self, entity: impl EntityKey, bundle: B) -> bool {
+        match entity.resolve_in(self) {
+            Some(entity) => {
+                bundle.insert(self, entity);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+macro_rules! impl_component_bundle {
+    ($($G:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($G: 'static),+> ComponentBundle for ($($G,)+) {
+            fn insert(self, scene: &mut Scene, entity: usize) {
+                let ($($G,)+) = self;
+
+                $(scene.component(entity, $G);)+
+            }
+        }
+    };
+}
+
+impl_component_bundle!(A);
+impl_component_bundle!(A, B);
+impl_component_bundle!(A, B, C);
+impl_component_bundle!(A, B, C, D);
+impl_component_bundle!(A, B, C, D, E);
+impl_component_bundle!(A, B, C, D, E, F);
+impl_component_bundle!(A, B, C, D, E, F, G);
+impl_component_bundle!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    #[test]
+    fn inserting_a_bundle_adds_the_entity_to_every_pool() {
+        let mut scene = Scene::new();
+
+        scene.entity();
+        let entity = scene.entity();
+        scene.components(entity, (32_i32, 1.5_f32, String::from("ignition")));
+
+        assert_eq!(scene.entities_with::<i32>(), &[entity.raw_id()]);
+        assert_eq!(scene.entities_with::<f32>(), &[entity.raw_id()]);
+        assert_eq!(scene.entities_with::<String>(), &[entity.raw_id()]);
+        assert_eq!(scene.get_component::<f32>(entity), Some(&1.5));
+    }
+
+    #[test]
+    fn inserting_a_bundle_into_existing_pools_appends_to_them() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, 32_i32);
+        let entity2 = scene.entity();
+        scene.components(entity2, (21_i32, 7_u8));
+
+        assert_eq!(
+            scene.get::<i32>().iter().collect::<Vec<_>>(),
+            vec![&32, &21]
+        );
+        assert_eq!(scene.get_component::<u8>(entity2), Some(&7));
+    }
+
+    #[test]
+    fn inserting_an_eight_component_bundle_works() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.components(
+            entity,
+            (1_u8, 2_u16, 3_u32, 4_u64, 5_i8, 6_i16, 7_i32, 8_i64),
+        );
+
+        assert_eq!(scene.get_component::<u8>(entity), Some(&1));
+        assert_eq!(scene.get_component::<i64>(entity), Some(&8));
+    }
+}
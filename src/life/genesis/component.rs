@@ -1,33 +1,104 @@
 use std::any::TypeId;
 
-use crate::life::{ComponentPool, Scene};
+use crate::life::{genesis::entity::EntityKey, ComponentPool, Scene};
 
 impl Scene {
-    pub fn component<G: 'static>(&mut self, entity: usize, component: G) {
+    // Returns false and inserts nothing when the handle is stale.
+    pub fn component<G: 'static>(&mut self, entity: impl EntityKey, component: G) -> bool {
+        let entity = match entity.resolve_in(self) {
+            Some(entity) => entity,
+            None => return false,
+        };
+
         if self.component_pool_exists::<G>() {
             self.assign_component(entity, component);
         } else {
             self.new_component_pool(entity, component);
         }
+
+        true
     }
 
-    pub fn vectorized_component<G: 'static>(&mut self, entity: usize, component: G) {
-        if self.component_exists::<Vec<G>>(entity) {
-            self.get_component_mut::<Vec<G>>(entity).push(component);
+    pub fn vectorized_component<G: 'static>(
+        &mut self,
+        entity: impl EntityKey,
+        component: G,
+    ) -> bool {
+        if let Some(components) = self.get_component_mut::<Vec<G>>(entity) {
+            components.push(component);
+            true
         } else {
-            self.component(entity, vec![component]);
+            self.component(entity, vec![component])
+        }
+    }
+
+    // Both drop the whole Vec<G> component once its last value is gone.
+    pub fn retain_vectorized<G: 'static, F>(&mut self, entity: impl EntityKey, predicate: F)
+    where
+        F: FnMut(&G) -> bool,
+    {
+        let entity = match entity.resolve_in(self) {
+            Some(entity) => entity,
+            None => return,
+        };
+
+        if let Some(components) = self.get_component_mut::<Vec<G>>(entity) {
+            components.retain(predicate);
+            self.remove_empty_vectorized::<G>(entity);
+        }
+    }
+
+    pub fn remove_vectorized_at<G: 'static>(
+        &mut self,
+        entity: impl EntityKey,
+        index: usize,
+    ) -> Option<G> {
+        let entity = entity.resolve_in(self)?;
+        let components = self.get_component_mut::<Vec<G>>(entity)?;
+        if index >= components.len() {
+            return None;
+        }
+
+        let component = components.remove(index);
+        self.remove_empty_vectorized::<G>(entity);
+
+        Some(component)
+    }
+
+    fn remove_empty_vectorized<G: 'static>(&mut self, entity: usize) {
+        if self
+            .get_component::<Vec<G>>(entity)
+            .is_some_and(|components| components.is_empty())
+        {
+            self.remove_component::<Vec<G>>(entity);
         }
     }
 
+    // Overwriting a component counts as removing the old one and inserting the new one.
     pub fn assign_component<G: 'static>(&mut self, entity: usize, component: G) {
+        self.run_remove_hooks(TypeId::of::<G>(), entity);
         self.get_mut::<G>().assign_component(entity, component);
+        self.run_insert_hooks::<G>(entity);
     }
 
     pub fn new_component_pool<G: 'static>(&mut self, entity: usize, component: G) {
-        let type_id = TypeId::of::<G>();
-        let component_pool = Box::new(ComponentPool::new_with_entity(entity, component));
+        if self.capacity > entity {
+            self.new_empty_component_pool::<G>();
+            self.get_mut::<G>().assign_component(entity, component);
+        } else {
+            self.component_pools.insert(
+                TypeId::of::<G>(),
+                Box::new(ComponentPool::new_with_entity(entity, component)),
+            );
+        }
+        self.run_insert_hooks::<G>(entity);
+    }
 
-        self.component_pools.insert(type_id, component_pool);
+    pub fn new_empty_component_pool<G: 'static>(&mut self) {
+        self.component_pools.insert(
+            TypeId::of::<G>(),
+            Box::new(ComponentPool::<G>::with_capacity(self.capacity, 0)),
+        );
     }
 }
 
@@ -36,11 +107,21 @@ impl<G> ComponentPool<G> {
         if self.has_component(entity) {
             *self.get_mut(entity) = component;
         } else {
-            Self::add_entity_to_sparse_array(entity, self.num_components, &mut self.sparse_array);
+            let index = self.packed_array.len();
+            Self::add_entity_to_sparse_array(entity, index, &mut self.sparse_array);
 
             self.packed_array.push(entity);
             self.component_array.push(component);
+
+            // Disabled components sit behind the enabled ones, the first of them trades places with the new one.
+            if index > self.num_components {
+                let first_disabled = self.packed_array[self.num_components];
+                self.sparse_array.swap(entity, first_disabled);
+                self.packed_array.swap(index, self.num_components);
+                self.component_array.swap(index, self.num_components);
+            }
             self.num_components += 1;
+            self.mark_changed(entity);
         }
     }
 }
@@ -54,7 +135,7 @@ mod tests {
         let mut scene = Scene::new();
 
         let entity = scene.entity();
-        scene.component(entity, 34 as i32);
+        scene.component(entity, 34_i32);
 
         assert_eq!(scene.get::<i32>().iter().collect::<Vec<&i32>>(), vec![&34]);
     }
@@ -64,10 +145,10 @@ mod tests {
         let mut scene = Scene::new();
 
         let entity1 = scene.entity();
-        scene.component(entity1, 34 as i32);
+        scene.component(entity1, 34_i32);
 
         let entity2 = scene.entity();
-        scene.component(entity2, 25 as i32);
+        scene.component(entity2, 25_i32);
 
         assert_eq!(
             scene.get::<i32>().iter().collect::<Vec<&i32>>(),
@@ -80,20 +161,39 @@ mod tests {
         let mut scene = Scene::new();
         let entity = scene.entity();
 
-        scene.component(entity, 34 as i32);
-        scene.component(entity, 25 as i32);
+        scene.component(entity, 34_i32);
+        scene.component(entity, 25_i32);
 
         assert_eq!(scene.get::<i32>().iter().collect::<Vec<&i32>>(), vec![&25]);
     }
 
+    #[test]
+    fn assigning_next_to_disabled_components_keeps_them_disabled() {
+        let mut scene = Scene::new();
+        let disabled = scene.entity();
+        scene.component(disabled, 34_i32);
+        scene.disable::<i32>(disabled);
+
+        let entity = scene.entity();
+        scene.component(entity, 25_i32);
+
+        assert_eq!(
+            scene.get::<i32>().packed_array,
+            vec![entity.raw_id(), disabled.raw_id()]
+        );
+        assert_eq!(scene.get_component::<i32>(entity), Some(&25));
+        assert_eq!(scene.get_component::<i32>(disabled), Some(&34));
+        assert!(!scene.get::<i32>().is_enabled(disabled.raw_id()));
+    }
+
     #[test]
     fn creating_vectorized_component_encapsulates_it_in_vector() {
         let mut scene = Scene::new();
 
         let entity = scene.entity();
-        scene.vectorized_component(entity, 34 as i32);
+        scene.vectorized_component(entity, 34_i32);
 
-        assert_eq!(scene.component_pool_exists::<Vec<i32>>(), true);
+        assert!(scene.component_pool_exists::<Vec<i32>>());
     }
 
     #[test]
@@ -101,12 +201,12 @@ mod tests {
         let mut scene = Scene::new();
 
         let entity1 = scene.entity();
-        scene.vectorized_component(entity1, 34 as i32);
-        scene.vectorized_component(entity1, 81 as i32);
+        scene.vectorized_component(entity1, 34_i32);
+        scene.vectorized_component(entity1, 81_i32);
 
         let entity2 = scene.entity();
-        scene.vectorized_component(entity2, 63 as u32);
-        scene.vectorized_component(entity2, 16 as u32);
+        scene.vectorized_component(entity2, 63_u32);
+        scene.vectorized_component(entity2, 16_u32);
 
         assert_eq!(
             scene.get::<Vec<i32>>().iter().collect::<Vec<&Vec<i32>>>(),
@@ -118,4 +218,95 @@ mod tests {
             vec![&vec![63, 16]]
         );
     }
+
+    #[test]
+    fn retaining_vectorized_components_filters_the_vector() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        for value in [3, 8, 5] {
+            scene.vectorized_component(entity, value);
+        }
+        scene.retain_vectorized::<i32, _>(entity, |value| *value > 4);
+
+        assert_eq!(
+            scene.get::<Vec<i32>>().iter().collect::<Vec<&Vec<i32>>>(),
+            vec![&vec![8, 5]]
+        );
+    }
+
+    #[test]
+    fn removing_the_last_vectorized_component_removes_the_vector() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        for value in [3, 8, 5] {
+            scene.vectorized_component(entity, value);
+        }
+        scene.retain_vectorized::<i32, _>(entity, |value| *value == 8);
+
+        assert_eq!(scene.remove_vectorized_at::<i32>(entity, 1), None);
+        assert_eq!(scene.remove_vectorized_at::<i32>(entity, 0), Some(8));
+        assert!(!scene.has::<Vec<i32>>(entity));
+        assert_eq!(scene.remove_vectorized_at::<i32>(entity, 0), None);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Player;
+
+    #[test]
+    fn many_zero_sized_components_can_be_inserted_and_removed() {
+        let mut scene = Scene::new();
+        let entities: Vec<usize> = (0..10_000).map(|_| scene.entity().raw_id()).collect();
+
+        for &entity in &entities {
+            scene.component(entity, Player);
+        }
+        for &entity in entities.iter().filter(|&&entity| entity % 3 == 0) {
+            assert_eq!(scene.remove_component::<Player>(entity), Some(Player));
+        }
+
+        let mut remaining: Vec<usize> = scene
+            .iter_with_entities::<Player>()
+            .map(|(entity, _player)| entity)
+            .collect();
+        remaining.sort_unstable();
+
+        assert_eq!(scene.component_count::<Player>(), 6_666);
+        assert_eq!(scene.iter::<Player>().count(), 6_666);
+        assert_eq!(
+            remaining,
+            entities
+                .iter()
+                .copied()
+                .filter(|entity| entity % 3 != 0)
+                .collect::<Vec<_>>()
+        );
+        assert!(!scene.has::<Player>(9_999));
+        assert_eq!(scene.get_component::<Player>(9_998), Some(&Player));
+    }
+
+    #[test]
+    fn zero_sized_components_behave_like_any_other() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        let entity3 = scene.entity();
+        scene.component(entity1, Player);
+        scene.component(entity3, Player);
+
+        assert_eq!(scene.get::<Player>().iter().count(), 2);
+        assert!(scene.has::<Player>(entity1));
+        assert!(!scene.has::<Player>(entity2));
+
+        scene.remove_component::<Player>(entity1);
+
+        assert_eq!(
+            scene.get::<Player>().iter().collect::<Vec<_>>(),
+            vec![&Player]
+        );
+        assert!(!scene.has::<Player>(entity1));
+        assert_eq!(scene.get_component::<Player>(entity3), Some(&Player));
+    }
 }
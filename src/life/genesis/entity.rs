@@ -1,52 +1,151 @@
-use crate::life::{ComponentPool, Scene};
+use crate::life::{ComponentPool, Entity, Scene};
 
-impl Scene {
-    pub fn entity(&mut self) -> usize {
-        if self.available_entities.len() == 1 {
-            self.generate_new_entity()
+// Handles are checked against the generation of their id. Raw ids, which pools, queries and the
+// renderer pass around, only have to be live.
+pub trait EntityKey: Copy + std::fmt::Debug {
+    fn resolve_in(self, scene: &Scene) -> Option<usize>;
+}
+
+impl EntityKey for usize {
+    fn resolve_in(self, scene: &Scene) -> Option<usize> {
+        if scene.is_live(self) {
+            Some(self)
         } else {
-            self.use_recycled_entity()
+            None
         }
     }
+}
+
+impl EntityKey for Entity {
+    fn resolve_in(self, scene: &Scene) -> Option<usize> {
+        scene.resolve(self)
+    }
+}
+
+impl Scene {
+    pub fn entity(&mut self) -> Entity {
+        let entity = self
+            .use_recycled_entity()
+            .unwrap_or_else(|| self.generate_new_entity());
+
+        self.handle(entity)
+    }
 
     pub fn generate_new_entity(&mut self) -> usize {
-        let id = self.available_entities[0];
-        self.available_entities[0] += 1;
+        let id = self.next_id;
+        self.next_id += 1;
+        self.alive.push(true);
 
         id
     }
 
-    pub fn use_recycled_entity(&mut self) -> usize {
-        self.available_entities.pop().unwrap()
+    pub fn use_recycled_entity(&mut self) -> Option<usize> {
+        let entity = self.free_list.pop()?;
+        self.alive[entity] = true;
+
+        Some(entity)
+    }
+
+    pub fn entities(&self) -> impl Iterator<Item = usize> + '_ {
+        self.alive
+            .iter()
+            .enumerate()
+            .filter(|(_entity, &alive)| alive)
+            .map(|(entity, _alive)| entity)
+    }
+
+    // Rebuilds `alive` after `next_id` and `free_list` were replaced wholesale, by a restore for instance.
+    pub fn recount_live_entities(&mut self) {
+        self.alive = vec![true; self.next_id];
+        for &entity in &self.free_list {
+            self.alive[entity] = false;
+        }
+    }
+
+    pub fn entity_count(&self) -> usize {
+        self.next_id - self.free_list.len()
+    }
+
+    pub fn is_live(&self, entity: usize) -> bool {
+        self.alive.get(entity).copied().unwrap_or(false)
+    }
+
+    pub fn handle(&self, entity: usize) -> Entity {
+        Entity {
+            id: entity,
+            generation: self.generation(entity),
+        }
+    }
+
+    pub fn generation(&self, entity: usize) -> u32 {
+        self.generations.get(entity).copied().unwrap_or(0)
+    }
+
+    pub fn is_alive(&self, entity: Entity) -> bool {
+        entity.generation == self.generation(entity.id) && self.is_live(entity.id)
+    }
+
+    pub fn resolve(&self, entity: Entity) -> Option<usize> {
+        if self.is_alive(entity) {
+            Some(entity.id)
+        } else {
+            None
+        }
+    }
+}
+
+impl Entity {
+    pub fn raw_id(&self) -> usize {
+        self.id
+    }
+
+    pub fn generation(&self) -> u32 {
+        self.generation
     }
 }
 
 impl<G> ComponentPool<G> {
-    pub fn add_entity_to_sparse_array(entity: usize, value: usize, sparse_array: &mut Vec<i32>) {
+    pub fn add_entity_to_sparse_array(
+        entity: usize,
+        value: usize,
+        sparse_array: &mut Vec<Option<u32>>,
+    ) {
         Self::prolong_sparse_array(entity, sparse_array);
-        sparse_array[entity] = value as i32;
+        sparse_array[entity] = Some(sparse_index(value));
     }
 
-    pub fn prolong_sparse_array(entity: usize, sparse_array: &mut Vec<i32>) {
+    pub fn prolong_sparse_array(entity: usize, sparse_array: &mut Vec<Option<u32>>) {
         if entity + 1 > sparse_array.len() {
-            sparse_array.resize(entity + 1, -1);
+            sparse_array.resize(entity + 1, None);
         }
     }
 }
 
+pub fn sparse_index(value: usize) -> u32 {
+    u32::try_from(value).unwrap_or_else(|_| {
+        panic!(
+            "Error: Component index {} does not fit in the sparse array - Ignition",
+            value
+        )
+    })
+}
+
 pub trait EntityConstructor {
     fn create_empty_entity(&mut self);
 }
 
 impl<G: 'static> EntityConstructor for ComponentPool<G> {
     fn create_empty_entity(&mut self) {
-        self.sparse_array.push(-1);
+        self.sparse_array.push(None);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::life::{genesis::entity::EntityConstructor, ComponentPool, Scene};
+    use crate::life::{
+        genesis::entity::{sparse_index, EntityConstructor},
+        ComponentPool, Scene,
+    };
 
     #[test]
     fn creating_an_entity_increments_an_id() {
@@ -54,7 +153,7 @@ mod tests {
         let mut entities: Vec<usize> = Vec::new();
 
         for _i in 0..10 {
-            entities.push(scene.entity());
+            entities.push(scene.entity().raw_id());
         }
 
         assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9], entities);
@@ -67,27 +166,27 @@ mod tests {
         let entity = scene.entity();
         scene.delete(entity);
 
-        assert_eq!(0, scene.entity());
+        assert_eq!(0, scene.entity().raw_id());
     }
 
     #[test]
     fn prolonging_sparse_array_works_as_intended() {
-        let mut sparse_array = vec![-1, -1, 0];
+        let mut sparse_array = vec![None, None, Some(0)];
         ComponentPool::<i32>::prolong_sparse_array(5, &mut sparse_array);
 
-        assert_eq!(vec![-1, -1, 0, -1, -1, -1], sparse_array,);
+        assert_eq!(vec![None, None, Some(0), None, None, None], sparse_array,);
     }
 
     #[test]
     fn prolonging_sparse_array_with_a_smaller_than_length_id_does_nothing() {
-        let mut sparse_array = vec![-1, -1, 0];
+        let mut sparse_array = vec![None, None, Some(0)];
         ComponentPool::<i32>::prolong_sparse_array(2, &mut sparse_array);
 
-        assert_eq!(vec![-1, -1, 0], sparse_array,);
+        assert_eq!(vec![None, None, Some(0)], sparse_array,);
     }
 
     #[test]
-    fn adding_a_new_empty_entity_appends_minus_one_to_sparse_array() {
+    fn adding_a_new_empty_entity_appends_an_empty_slot_to_sparse_array() {
         let mut pool = ComponentPool::new_with_entity(3, 32);
         pool.create_empty_entity();
 
@@ -96,10 +195,207 @@ mod tests {
             ComponentPool {
                 num_components: 1,
 
-                sparse_array: vec![-1, -1, -1, 0, -1],
+                sparse_array: vec![None, None, None, Some(0), None],
                 packed_array: vec![3],
                 component_array: vec![32],
+
+                changed: vec![false, false, false, true],
             },
         );
     }
+
+    #[test]
+    fn handles_of_live_entities_are_alive() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+
+        assert_eq!(scene.handle(entity.raw_id()), entity);
+        assert_eq!(entity.generation(), 0);
+        assert!(scene.is_alive(entity));
+        assert_eq!(scene.resolve(entity), Some(entity.raw_id()));
+    }
+
+    #[test]
+    fn handles_of_deleted_entities_are_stale() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.delete(entity);
+
+        assert!(!scene.is_alive(entity));
+        assert_eq!(scene.resolve(entity), None);
+    }
+
+    #[test]
+    fn recycled_entities_do_not_revive_stale_handles() {
+        let mut scene = Scene::new();
+
+        let stale = scene.entity();
+        scene.delete(stale);
+
+        let fresh = scene.entity();
+
+        assert_eq!(fresh.raw_id(), stale.raw_id());
+        assert_eq!(fresh.generation(), 1);
+        assert!(!scene.is_alive(stale));
+        assert!(scene.is_alive(fresh));
+    }
+
+    #[test]
+    fn deleting_through_a_stale_handle_is_refused() {
+        let mut scene = Scene::new();
+
+        let stale = scene.entity();
+        scene.delete(stale);
+
+        let recycled = scene.entity();
+        scene.component(recycled, 32_i32);
+
+        assert!(!scene.delete(stale));
+        assert!(scene.component_exists::<i32>(recycled));
+    }
+
+    #[test]
+    fn stale_handles_cannot_reach_the_components_of_a_recycled_entity() {
+        let mut scene = Scene::new();
+
+        let stale = scene.entity();
+        scene.delete(stale);
+        let recycled = scene.entity();
+        scene.component(recycled, 32_i32);
+
+        assert!(!scene.component(stale, 21_i32));
+        assert_eq!(scene.get_component::<i32>(stale), None);
+        assert_eq!(scene.get_component_mut::<i32>(stale), None);
+        assert_eq!(scene.remove_component::<i32>(stale), None);
+        assert!(!scene.has::<i32>(stale));
+        assert!(!scene.disable::<i32>(stale));
+        assert_eq!(scene.get_component::<i32>(recycled), Some(&32));
+    }
+
+    #[test]
+    #[should_panic(expected = "was deleted - Ignition")]
+    fn taking_a_component_through_a_stale_handle_panics() {
+        let mut scene = Scene::new();
+
+        let stale = scene.entity();
+        scene.component(stale, 32_i32);
+        scene.delete(stale);
+
+        scene.take_component::<i32>(stale);
+    }
+
+    #[test]
+    fn raw_ids_of_deleted_entities_are_refused() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.delete(entity);
+
+        assert!(!scene.component(entity.raw_id(), 32_i32));
+        assert_eq!(scene.get_component::<i32>(entity.raw_id()), None);
+
+        let recycled = scene.entity();
+        assert!(!scene.has::<i32>(recycled));
+        assert!(scene.component(recycled.raw_id(), 21_i32));
+        assert_eq!(scene.get_component::<i32>(recycled), Some(&21));
+    }
+
+    #[test]
+    fn handles_of_never_created_entities_are_not_alive() {
+        let scene = Scene::new();
+
+        assert!(!scene.is_alive(scene.handle(3)));
+    }
+
+    #[test]
+    fn iterating_entities_yields_only_live_entities() {
+        let mut scene = Scene::new();
+
+        let entities: Vec<usize> = (0..5).map(|_| scene.entity().raw_id()).collect();
+        scene.delete(entities[1]);
+        scene.delete(entities[3]);
+
+        assert_eq!(
+            scene.entities().collect::<Vec<usize>>(),
+            vec![entities[0], entities[2], entities[4]]
+        );
+        assert_eq!(scene.entity_count(), 3);
+    }
+
+    #[test]
+    fn recycling_an_entity_makes_it_live_again() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.delete(entity1);
+        scene.entity();
+
+        assert_eq!(
+            scene.entities().collect::<Vec<usize>>(),
+            vec![entity1.raw_id(), entity2.raw_id()]
+        );
+        assert_eq!(scene.entity_count(), 2);
+    }
+
+    #[test]
+    fn liveness_follows_deletes_recycling_and_restores() {
+        let mut scene = Scene::new();
+
+        let entities: Vec<usize> = (0..4).map(|_| scene.entity().raw_id()).collect();
+        scene.delete(entities[1]);
+        scene.delete(entities[2]);
+        scene.entity();
+
+        assert!(scene.is_live(entities[2]));
+        assert!(!scene.is_live(entities[1]));
+        assert!(!scene.is_live(4));
+
+        scene.free_list = vec![0, 3];
+        scene.recount_live_entities();
+
+        assert_eq!(scene.entities().collect::<Vec<usize>>(), vec![1, 2]);
+        assert!(!scene.is_live(3));
+    }
+
+    #[test]
+    fn sparse_indices_keep_values_that_fit() {
+        assert_eq!(sparse_index(0), 0);
+        assert_eq!(sparse_index(u32::MAX as usize), u32::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in the sparse array")]
+    #[cfg(target_pointer_width = "64")]
+    fn sparse_indices_refuse_values_that_would_truncate() {
+        sparse_index(u32::MAX as usize + 1);
+    }
+
+    #[test]
+    fn recycling_many_entities_never_hands_out_a_live_id() {
+        let mut scene = Scene::new();
+        let mut live: Vec<usize> = (0..100).map(|_| scene.entity().raw_id()).collect();
+
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..50 {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let entity = live.swap_remove((seed >> 33) as usize % live.len());
+            scene.delete(entity);
+        }
+
+        for _ in 0..50 {
+            let entity = scene.entity().raw_id();
+            assert!(!live.contains(&entity), "{} was handed out twice", entity);
+            live.push(entity);
+        }
+
+        live.sort_unstable();
+        assert_eq!(live, (0..100).collect::<Vec<_>>());
+        assert_eq!(scene.entity().raw_id(), 100);
+        assert_eq!(scene.entity_count(), 101);
+    }
 }
@@ -0,0 +1,85 @@
+use crate::life::{Entity, Scene};
+
+pub struct EntityBuilder<'a> {
+    scene: &'a mut Scene,
+    entity: Entity,
+}
+
+impl Scene {
+    pub fn spawn(&mut self) -> EntityBuilder<'_> {
+        let entity = self.entity();
+
+        EntityBuilder {
+            scene: self,
+            entity,
+        }
+    }
+}
+
+impl<'a> EntityBuilder<'a> {
+    pub fn with<G: 'static>(self, component: G) -> Self {
+        self.scene.component(self.entity, component);
+        self
+    }
+
+    pub fn with_vectorized<G: 'static>(self, component: G) -> Self {
+        self.scene.vectorized_component(self.entity, component);
+        self
+    }
+
+    pub fn id(self) -> Entity {
+        self.entity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    #[test]
+    fn spawning_an_entity_with_three_components_adds_it_to_every_pool() {
+        let mut scene = Scene::new();
+
+        scene.entity();
+        let entity = scene
+            .spawn()
+            .with(32_i32)
+            .with(1.5_f32)
+            .with(String::from("ignition"))
+            .id();
+
+        assert_eq!(entity.raw_id(), 1);
+        assert_eq!(scene.get_component::<i32>(entity), Some(&32));
+        assert_eq!(scene.get_component::<f32>(entity), Some(&1.5));
+        assert_eq!(
+            scene.get_component::<String>(entity),
+            Some(&String::from("ignition"))
+        );
+    }
+
+    #[test]
+    fn spawning_with_vectorized_components_collects_them() {
+        let mut scene = Scene::new();
+
+        let entity = scene
+            .spawn()
+            .with_vectorized(1_u8)
+            .with_vectorized(2_u8)
+            .id();
+
+        assert_eq!(scene.get_component::<Vec<u8>>(entity), Some(&vec![1, 2]));
+    }
+
+    #[test]
+    fn spawning_reuses_recycled_entities() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.delete(entity);
+
+        let recycled = scene.spawn().with(32_i32).id();
+
+        assert_eq!(recycled.raw_id(), entity.raw_id());
+        assert_eq!(recycled.generation(), 1);
+    }
+}
@@ -1,16 +1,33 @@
-use crate::life::{gizmos::PoolToolbox, ComponentPool, Scene};
+use crate::life::{
+    genesis::entity::EntityKey, gizmos::PoolToolbox, ComponentPool, ComponentPoolTrait, Scene,
+};
 
 impl Scene {
-    pub fn toggle<G: 'static>(&mut self, entity: usize) {
-        self.get_trait_mut::<G>().toggle_entity(entity);
+    // Stale handles are refused with false, like the rest of the entity API.
+    pub fn toggle<G: 'static>(&mut self, entity: impl EntityKey) -> bool {
+        self.toggle_with::<G>(entity, |pool, entity| pool.toggle_entity(entity))
     }
 
-    pub fn enable<G: 'static>(&mut self, entity: usize) {
-        self.get_trait_mut::<G>().enable_entity(entity);
+    pub fn enable<G: 'static>(&mut self, entity: impl EntityKey) -> bool {
+        self.toggle_with::<G>(entity, |pool, entity| pool.enable_entity(entity))
     }
 
-    pub fn disable<G: 'static>(&mut self, entity: usize) {
-        self.get_trait_mut::<G>().disable_entity(entity);
+    pub fn disable<G: 'static>(&mut self, entity: impl EntityKey) -> bool {
+        self.toggle_with::<G>(entity, |pool, entity| pool.disable_entity(entity))
+    }
+
+    fn toggle_with<G: 'static>(
+        &mut self,
+        entity: impl EntityKey,
+        toggle: impl FnOnce(&mut Box<dyn ComponentPoolTrait>, usize),
+    ) -> bool {
+        match entity.resolve_in(self) {
+            Some(entity) => {
+                toggle(self.get_trait_mut::<G>(), entity);
+                true
+            }
+            None => false,
+        }
     }
 }
 
@@ -44,11 +61,11 @@ impl<G: 'static> ComponentToggler for ComponentPool<G> {
     /* Utility functions */
 
     fn entity_in_scope(&mut self, entity: usize) -> bool {
-        (self.sparse_array[entity] as usize) < self.num_components
+        self.index_of(entity).unwrap() < self.num_components
     }
 
     fn move_to_back(&mut self, entity: usize) {
-        let component = self.sparse_array[entity] as usize;
+        let component = self.index_of(entity).unwrap();
         let component_destination = self.num_components - 1;
 
         let entity_destination = self.packed_array[component_destination];
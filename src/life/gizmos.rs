@@ -1,13 +1,48 @@
-use crate::life::{ComponentPool, Scene};
+use crate::life::{genesis::entity::EntityKey, ComponentPool, Scene};
 use std::any::TypeId;
 
 impl Scene {
-    pub fn component_pool_exists<G: 'static>(&mut self) -> bool {
+    pub fn component_pool_exists<G: 'static>(&self) -> bool {
         self.component_pools.contains_key(&TypeId::of::<G>())
     }
 
-    pub fn component_exists<G: 'static>(&mut self, entity: usize) -> bool {
-        self.component_pool_exists::<G>() && self.get::<G>().has_component(entity)
+    pub fn component_exists<G: 'static>(&self, entity: impl EntityKey) -> bool {
+        self.has::<G>(entity)
+    }
+
+    pub fn has<G: 'static>(&self, entity: impl EntityKey) -> bool {
+        match (self.try_get::<G>(), entity.resolve_in(self)) {
+            (Some(component_pool), Some(entity)) => component_pool.has_component(entity),
+            _ => false,
+        }
+    }
+
+    pub fn component_count<G: 'static>(&self) -> usize {
+        self.try_get::<G>()
+            .map_or(0, |component_pool| component_pool.num_components)
+    }
+
+    pub fn entities_with<G: 'static>(&self) -> &[usize] {
+        match self.try_get::<G>() {
+            Some(component_pool) => &component_pool.packed_array,
+            None => &[],
+        }
+    }
+
+    pub fn iter<G: 'static>(&self) -> impl Iterator<Item = &G> {
+        self.get::<G>().iter()
+    }
+
+    pub fn iter_mut<G: 'static>(&mut self) -> impl Iterator<Item = &mut G> {
+        self.get_mut::<G>().iter_mut()
+    }
+
+    pub fn iter_with_entities<G: 'static>(&self) -> impl Iterator<Item = (usize, &G)> {
+        self.get::<G>().iter_with_entities()
+    }
+
+    pub fn iter_with_entities_mut<G: 'static>(&mut self) -> impl Iterator<Item = (usize, &mut G)> {
+        self.get_mut::<G>().iter_with_entities_mut()
     }
 }
 
@@ -19,13 +54,40 @@ impl<G> ComponentPool<G> {
     }
 
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut G> {
+        self.iter_with_entities_mut()
+            .map(|(_entity, component)| component)
+    }
+
+    pub fn iter_with_entities(&self) -> impl Iterator<Item = (usize, &G)> {
+        self.packed_array.iter().copied().zip(self.iter())
+    }
+
+    // Marks each component as changed when it is yielded.
+    pub fn iter_with_entities_mut(&mut self) -> impl Iterator<Item = (usize, &mut G)> {
+        self.prolong_changed();
+        let changed = &mut self.changed;
         let (left, _right) = self.component_array.split_at_mut(self.num_components);
 
-        left.iter_mut()
+        self.packed_array
+            .iter()
+            .copied()
+            .zip(left.iter_mut())
+            .map(move |(entity, component)| {
+                changed[entity] = true;
+                (entity, component)
+            })
     }
 
     pub fn has_component(&self, entity: usize) -> bool {
-        self.sparse_array.get(entity).unwrap_or(&-1) != &-1
+        self.index_of(entity).is_some()
+    }
+
+    pub fn index_of(&self, entity: usize) -> Option<usize> {
+        self.sparse_array
+            .get(entity)
+            .copied()
+            .flatten()
+            .map(|index| index as usize)
     }
 }
 
@@ -43,8 +105,8 @@ pub trait PoolToolbox {
 
 impl<G: 'static> PoolToolbox for ComponentPool<G> {
     fn swap_entities(&mut self, entity: usize, entity_destination: usize) {
-        let component = self.sparse_array[entity] as usize;
-        let component_destination = self.sparse_array[entity_destination] as usize;
+        let component = self.index_of(entity).unwrap();
+        let component_destination = self.index_of(entity_destination).unwrap();
 
         self.swap(entity, entity_destination, component, component_destination);
     }
@@ -71,26 +133,189 @@ impl<G: 'static> PoolToolbox for ComponentPool<G> {
 
 #[cfg(test)]
 mod tests {
-    use crate::life::ComponentPool;
+    use crate::life::{ComponentPool, Scene};
+
+    #[test]
+    fn iterating_mutably_updates_every_component() {
+        let mut scene = Scene::new();
+        for value in 1..=3 {
+            let entity = scene.entity();
+            scene.component(entity, value);
+        }
+
+        for value in scene.iter_mut::<i32>() {
+            *value *= 2;
+        }
+
+        assert_eq!(scene.iter::<i32>().collect::<Vec<_>>(), vec![&2, &4, &6]);
+    }
 
     #[test]
     fn entity_out_of_bounds_does_not_have_component() {
         let pool = ComponentPool::new_with_entity(3, 32);
 
-        assert_eq!(pool.has_component(4), false);
+        assert!(!pool.has_component(4));
     }
 
     #[test]
     fn entity_without_component_does_not_have_component() {
         let pool = ComponentPool::new_with_entity(3, 32);
 
-        assert_eq!(pool.has_component(2), false);
+        assert!(!pool.has_component(2));
     }
 
     #[test]
     fn entity_with_component_has_component() {
         let pool = ComponentPool::new_with_entity(3, 32);
 
-        assert_eq!(pool.has_component(3), true);
+        assert!(pool.has_component(3));
+    }
+
+    #[test]
+    fn iterating_with_entities_pairs_components_with_their_owners() {
+        let mut pool = ComponentPool::new_with_entity(3, 32);
+        pool.assign_component(7, 21);
+
+        assert_eq!(
+            pool.iter_with_entities().collect::<Vec<(usize, &i32)>>(),
+            vec![(3, &32), (7, &21)]
+        );
+    }
+
+    #[test]
+    fn iterating_with_entities_mutably_updates_components() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, 10_i32);
+        let entity2 = scene.entity();
+        scene.component(entity2, 20_i32);
+
+        for (entity, component) in scene.iter_with_entities_mut::<i32>() {
+            *component += entity as i32;
+        }
+
+        assert_eq!(
+            scene
+                .iter_with_entities::<i32>()
+                .collect::<Vec<(usize, &i32)>>(),
+            vec![(entity1.raw_id(), &10), (entity2.raw_id(), &21)]
+        );
+    }
+
+    #[test]
+    fn iterating_with_entities_skips_disabled_components() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, 10_i32);
+        let entity2 = scene.entity();
+        scene.component(entity2, 20_i32);
+
+        scene.disable::<i32>(entity1);
+
+        assert_eq!(
+            scene
+                .iter_with_entities::<i32>()
+                .collect::<Vec<(usize, &i32)>>(),
+            vec![(entity2.raw_id(), &20)]
+        );
+    }
+
+    #[test]
+    fn entities_with_a_missing_component_pool_is_empty() {
+        let scene = Scene::new();
+
+        assert_eq!(scene.entities_with::<i32>(), &[] as &[usize]);
+    }
+
+    #[test]
+    fn entities_with_a_component_follow_insertions_and_deletions() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, 10_i32);
+        let entity2 = scene.entity();
+        scene.component(entity2, 1.5_f32);
+        let entity3 = scene.entity();
+        scene.component(entity3, 30_i32);
+        let entity4 = scene.entity();
+        scene.component(entity4, 40_i32);
+
+        assert_eq!(
+            scene.entities_with::<i32>(),
+            &[entity1.raw_id(), entity3.raw_id(), entity4.raw_id()]
+        );
+
+        scene.remove_component::<i32>(entity1);
+
+        assert_eq!(
+            scene.entities_with::<i32>(),
+            &[entity4.raw_id(), entity3.raw_id()]
+        );
+        assert_eq!(scene.entities_with::<f32>(), &[entity2.raw_id()]);
+    }
+
+    #[test]
+    fn scene_has_nothing_without_a_component_pool() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+
+        assert!(!scene.has::<i32>(entity));
+    }
+
+    #[test]
+    fn scene_has_nothing_for_entities_beyond_the_sparse_array() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+        scene.component(entity, 10_i32);
+
+        assert!(!scene.has::<i32>(1_000_000));
+        assert_eq!(scene.get::<i32>().sparse_array.len(), 1);
+    }
+
+    #[test]
+    fn scene_has_nothing_for_entities_without_the_component() {
+        let mut scene = Scene::new();
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.component(entity2, 10_i32);
+
+        assert!(!scene.has::<i32>(entity1));
+    }
+
+    #[test]
+    fn scene_has_components_that_were_assigned() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+        scene.component(entity, 10_i32);
+
+        assert!(scene.has::<i32>(entity));
+    }
+
+    #[test]
+    fn component_count_of_a_missing_component_pool_is_zero() {
+        let scene = Scene::new();
+
+        assert_eq!(scene.component_count::<i32>(), 0);
+    }
+
+    #[test]
+    fn component_count_follows_live_components() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        scene.component(entity1, 10_i32);
+        let entity2 = scene.entity();
+        scene.component(entity2, 20_i32);
+        let entity3 = scene.entity();
+        scene.component(entity3, 30_i32);
+
+        assert_eq!(scene.component_count::<i32>(), 3);
+
+        scene.delete(entity2);
+        scene.disable::<i32>(entity3);
+
+        assert_eq!(scene.component_count::<i32>(), 1);
     }
 }
@@ -0,0 +1,131 @@
+use std::any::{Any, TypeId};
+
+use crate::life::Scene;
+
+pub type EventQueue = Box<dyn Any>;
+
+impl Scene {
+    pub fn emit<E: 'static>(&mut self, event: E) {
+        self.events
+            .entry(TypeId::of::<E>())
+            .or_insert_with(|| Box::new(Vec::<E>::new()))
+            .downcast_mut::<Vec<E>>()
+            .unwrap()
+            .push(event);
+    }
+
+    pub fn events<E: 'static>(&self) -> impl Iterator<Item = &E> {
+        self.events
+            .get(&TypeId::of::<E>())
+            .and_then(|queue| queue.downcast_ref::<Vec<E>>())
+            .map_or(&[][..], |queue| queue.as_slice())
+            .iter()
+    }
+
+    pub fn drain_events<E: 'static>(&mut self) -> impl Iterator<Item = E> {
+        self.events
+            .remove(&TypeId::of::<E>())
+            .and_then(|queue| queue.downcast::<Vec<E>>().ok())
+            .map_or_else(Vec::new, |queue| *queue)
+            .into_iter()
+    }
+
+    pub fn clear_events(&mut self) {
+        self.events.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    #[derive(Debug, PartialEq)]
+    struct Collision(usize, usize);
+
+    #[derive(Debug, PartialEq)]
+    struct ButtonPressed;
+
+    #[test]
+    fn events_are_queued_per_type() {
+        let mut scene = Scene::new();
+
+        scene.emit(Collision(0, 1));
+        scene.emit(ButtonPressed);
+        scene.emit(Collision(2, 3));
+
+        assert_eq!(
+            scene.events::<Collision>().collect::<Vec<_>>(),
+            vec![&Collision(0, 1), &Collision(2, 3)]
+        );
+        assert_eq!(scene.events::<ButtonPressed>().count(), 1);
+    }
+
+    #[test]
+    fn events_of_an_unknown_type_are_empty() {
+        let scene = Scene::new();
+
+        assert_eq!(scene.events::<Collision>().count(), 0);
+    }
+
+    #[test]
+    fn later_systems_see_events_from_the_same_frame() {
+        let mut scene = Scene::new();
+
+        scene.add_system(|scene| scene.emit(Collision(0, 1)));
+        scene.add_system(|scene| {
+            let collisions = scene.events::<Collision>().count();
+            scene.insert_resource(collisions);
+        });
+        scene.run_systems();
+
+        assert_eq!(*scene.resource::<usize>(), 1);
+    }
+
+    #[test]
+    fn draining_events_takes_them_in_order() {
+        let mut scene = Scene::new();
+
+        scene.emit(Collision(0, 1));
+        scene.emit(Collision(2, 3));
+
+        assert_eq!(
+            scene.drain_events::<Collision>().collect::<Vec<_>>(),
+            vec![Collision(0, 1), Collision(2, 3)]
+        );
+        assert_eq!(scene.events::<Collision>().count(), 0);
+    }
+
+    #[test]
+    fn draining_one_type_leaves_the_others_queued() {
+        let mut scene = Scene::new();
+
+        scene.emit(Collision(0, 1));
+        scene.emit(ButtonPressed);
+
+        assert_eq!(scene.drain_events::<Collision>().count(), 1);
+        assert_eq!(
+            scene.events::<ButtonPressed>().collect::<Vec<_>>(),
+            vec![&ButtonPressed]
+        );
+    }
+
+    #[test]
+    fn draining_an_unknown_type_yields_nothing() {
+        let mut scene = Scene::new();
+
+        assert_eq!(scene.drain_events::<Collision>().count(), 0);
+    }
+
+    #[test]
+    fn clearing_events_empties_every_queue() {
+        let mut scene = Scene::new();
+
+        scene.emit(Collision(0, 1));
+        scene.emit(Collision(2, 3));
+        scene.emit(ButtonPressed);
+        scene.clear_events();
+
+        assert_eq!(scene.events::<Collision>().count(), 0);
+        assert_eq!(scene.events::<ButtonPressed>().count(), 0);
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use crate::life::{genesis::entity::EntityKey, ComponentPool, Scene};
+
+#[derive(Debug, Default, Clone)]
+pub struct TagTable {
+    ids: HashMap<String, usize>,
+    names: Vec<String>,
+    pools: Vec<ComponentPool<()>>,
+}
+
+impl TagTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.ids.insert(name.to_owned(), id);
+        self.names.push(name.to_owned());
+        self.pools.push(ComponentPool::new());
+
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> Option<&str> {
+        self.names.get(id).map(String::as_str)
+    }
+
+    pub fn pool(&self, name: &str) -> Option<&ComponentPool<()>> {
+        self.pools.get(self.id(name)?)
+    }
+
+    pub fn pool_mut(&mut self, name: &str) -> Option<&mut ComponentPool<()>> {
+        let id = self.id(name)?;
+        self.pools.get_mut(id)
+    }
+
+    pub fn tagged(&self) -> impl Iterator<Item = (&str, &[usize])> {
+        self.names
+            .iter()
+            .zip(self.pools.iter())
+            .map(|(name, pool)| (name.as_str(), pool.packed_array.as_slice()))
+    }
+
+    pub fn delete_entity(&mut self, entity: usize) {
+        for pool in self.pools.iter_mut() {
+            pool.remove(entity);
+        }
+    }
+}
+
+impl Scene {
+    pub fn tag_id(&mut self, tag: &str) -> usize {
+        self.tags.intern(tag)
+    }
+
+    pub fn add_tag(&mut self, entity: impl EntityKey, tag: &str) -> bool {
+        let entity = match entity.resolve_in(self) {
+            Some(entity) => entity,
+            None => return false,
+        };
+
+        let id = self.tags.intern(tag);
+        self.tags.pools[id].assign_component(entity, ());
+
+        true
+    }
+
+    pub fn remove_tag(&mut self, entity: impl EntityKey, tag: &str) -> bool {
+        let entity = match entity.resolve_in(self) {
+            Some(entity) => entity,
+            None => return false,
+        };
+
+        self.tags
+            .pool_mut(tag)
+            .and_then(|pool| pool.remove(entity))
+            .is_some()
+    }
+
+    pub fn has_tag(&self, entity: impl EntityKey, tag: &str) -> bool {
+        match (self.tags.pool(tag), entity.resolve_in(self)) {
+            (Some(pool), Some(entity)) => pool.has_component(entity),
+            _ => false,
+        }
+    }
+
+    pub fn with_tag(&self, tag: &str) -> impl Iterator<Item = usize> + '_ {
+        self.tags
+            .pool(tag)
+            .map_or(&[][..], |pool| pool.packed_array.as_slice())
+            .iter()
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    #[test]
+    fn interning_the_same_tag_twice_returns_the_same_id() {
+        let mut scene = Scene::new();
+
+        let enemy = scene.tag_id("enemy");
+        let checkpoint = scene.tag_id("checkpoint:3");
+
+        assert_eq!(scene.tag_id("enemy"), enemy);
+        assert_ne!(enemy, checkpoint);
+        assert_eq!(scene.tags.name(checkpoint), Some("checkpoint:3"));
+    }
+
+    #[test]
+    fn an_entity_can_carry_multiple_tags() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.add_tag(entity, "enemy");
+        scene.add_tag(entity, "flying");
+        scene.add_tag(entity, "enemy");
+
+        assert!(scene.has_tag(entity, "enemy"));
+        assert!(scene.has_tag(entity, "flying"));
+        assert!(!scene.has_tag(entity, "checkpoint:3"));
+        assert_eq!(scene.with_tag("enemy").count(), 1);
+    }
+
+    #[test]
+    fn removing_a_tag_only_affects_that_tag() {
+        let mut scene = Scene::new();
+
+        let entity = scene.entity();
+        scene.add_tag(entity, "enemy");
+        scene.add_tag(entity, "flying");
+
+        assert!(scene.remove_tag(entity, "enemy"));
+        assert!(!scene.remove_tag(entity, "enemy"));
+        assert!(!scene.remove_tag(entity, "unknown"));
+
+        assert!(!scene.has_tag(entity, "enemy"));
+        assert!(scene.has_tag(entity, "flying"));
+    }
+
+    #[test]
+    fn deleting_an_entity_removes_its_tags() {
+        let mut scene = Scene::new();
+
+        let entity1 = scene.entity();
+        let entity2 = scene.entity();
+        scene.add_tag(entity1, "enemy");
+        scene.add_tag(entity2, "enemy");
+
+        scene.delete(entity1);
+
+        assert_eq!(
+            scene.with_tag("enemy").collect::<Vec<usize>>(),
+            vec![entity2.raw_id()]
+        );
+        assert!(!scene.has_tag(entity1, "enemy"));
+    }
+
+    #[test]
+    fn iterating_a_tag_yields_every_tagged_entity() {
+        let mut scene = Scene::new();
+
+        for i in 0..10 {
+            let entity = scene.entity();
+
+            if i % 3 == 0 {
+                scene.add_tag(entity, "checkpoint");
+            }
+        }
+
+        assert_eq!(
+            scene.with_tag("checkpoint").collect::<Vec<usize>>(),
+            vec![0, 3, 6, 9]
+        );
+        assert_eq!(scene.with_tag("unknown").count(), 0);
+    }
+}
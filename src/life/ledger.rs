@@ -0,0 +1,129 @@
+use std::any::type_name;
+use std::mem::size_of;
+
+use crate::life::{chronicle::short_type_name, ComponentPool, Scene};
+
+// Bytes are what the arrays have allocated, not just what is in use.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PoolStats {
+    pub type_name: String,
+    pub components: usize,
+    pub sparse_len: usize,
+
+    pub sparse_bytes: usize,
+    pub packed_bytes: usize,
+    pub component_bytes: usize,
+}
+
+impl PoolStats {
+    pub fn total_bytes(&self) -> usize {
+        self.sparse_bytes + self.packed_bytes + self.component_bytes
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SceneStats {
+    pub entities: usize,
+    pub free_list: usize,
+    pub pools: Vec<PoolStats>,
+}
+
+impl SceneStats {
+    pub fn pool(&self, type_name: &str) -> Option<&PoolStats> {
+        self.pools.iter().find(|pool| pool.type_name == type_name)
+    }
+
+    pub fn total_bytes(&self) -> usize {
+        self.pools.iter().map(PoolStats::total_bytes).sum()
+    }
+}
+
+impl Scene {
+    // Pools are sorted by type name so the output is stable between runs.
+    pub fn stats(&self) -> SceneStats {
+        let mut pools: Vec<PoolStats> = self
+            .component_pools
+            .values()
+            .map(|component_pool| component_pool.stats())
+            .collect();
+        pools.sort_by(|left, right| left.type_name.cmp(&right.type_name));
+
+        SceneStats {
+            entities: self.entity_count(),
+            free_list: self.free_list.len(),
+            pools,
+        }
+    }
+}
+
+impl<G> ComponentPool<G> {
+    pub fn stats(&self) -> PoolStats {
+        PoolStats {
+            type_name: short_type_name(type_name::<G>()),
+            components: self.packed_array.len(),
+            sparse_len: self.sparse_array.len(),
+
+            sparse_bytes: self.sparse_array.capacity() * size_of::<Option<u32>>(),
+            packed_bytes: self.packed_array.capacity() * size_of::<usize>(),
+            component_bytes: self.component_array.capacity() * size_of::<G>(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use crate::life::Scene;
+
+    #[test]
+    fn one_high_entity_shows_up_in_the_sparse_length() {
+        let mut scene = Scene::new();
+        for _ in 0..=10_000 {
+            scene.entity();
+        }
+        scene.component(0, 1.5_f64);
+        scene.component(10_000, 2.5_f64);
+
+        let stats = scene.stats();
+        let pool = stats.pool("f64").unwrap();
+
+        assert_eq!(pool.components, 2);
+        assert_eq!(pool.sparse_len, 10_001);
+        assert!(pool.sparse_bytes >= 10_001 * size_of::<Option<u32>>());
+        assert!(pool.component_bytes >= 2 * size_of::<f64>());
+        assert!(pool.component_bytes < pool.sparse_bytes);
+    }
+
+    #[test]
+    fn scene_stats_count_entities_and_the_free_list() {
+        let mut scene = Scene::new();
+        let entities: Vec<usize> = (0..5).map(|_| scene.entity().raw_id()).collect();
+        scene.delete(entities[1]);
+        scene.delete(entities[3]);
+
+        let stats = scene.stats();
+
+        assert_eq!(stats.entities, 3);
+        assert_eq!(stats.free_list, 2);
+        assert_eq!(stats.pools, vec![]);
+    }
+
+    #[test]
+    fn pools_are_listed_by_type_name() {
+        let mut scene = Scene::new();
+        let entity = scene.entity();
+        scene.component(entity, 1_u8);
+        scene.component(entity, 'c');
+        scene.component(entity, 1_i32);
+
+        let names: Vec<String> = scene
+            .stats()
+            .pools
+            .into_iter()
+            .map(|pool| pool.type_name)
+            .collect();
+
+        assert_eq!(names, vec!["char", "i32", "u8"]);
+    }
+}
@@ -0,0 +1,239 @@
+use crate::life::{abduction::stale_entity, genesis::entity::EntityKey, Scene};
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Parent(pub usize);
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Children(pub Vec<usize>);
+
+impl Scene {
+    // Parent and Children keep raw ids. `delete` detaches an entity from both sides, see `detach`.
+    pub fn set_parent(&mut self, child: impl EntityKey, parent: impl EntityKey) {
+        let child = child
+            .resolve_in(self)
+            .unwrap_or_else(|| stale_entity(child));
+        let parent = parent
+            .resolve_in(self)
+            .unwrap_or_else(|| stale_entity(parent));
+        if self.is_ancestor(child, parent) {
+            panic!(
+                "Error: Parenting entity {} to {} would create a cycle - Ignition",
+                child, parent
+            );
+        }
+
+        self.remove_parent(child);
+        self.component(child, Parent(parent));
+
+        match self.get_component_mut::<Children>(parent) {
+            Some(children) => children.0.push(child),
+            None => {
+                self.component(parent, Children(vec![child]));
+            }
+        }
+    }
+
+    pub fn remove_parent(&mut self, child: impl EntityKey) -> Option<usize> {
+        let child = child.resolve_in(self)?;
+        let Parent(parent) = self.remove_component::<Parent>(child)?;
+
+        if let Some(children) = self.get_component_mut::<Children>(parent) {
+            children.0.retain(|&sibling| sibling != child);
+        }
+
+        Some(parent)
+    }
+
+    pub fn is_ancestor(&self, ancestor: impl EntityKey, entity: impl EntityKey) -> bool {
+        let (ancestor, mut current) = match (ancestor.resolve_in(self), entity.resolve_in(self)) {
+            (Some(ancestor), Some(entity)) => (ancestor, entity),
+            _ => return false,
+        };
+
+        loop {
+            if current == ancestor {
+                return true;
+            }
+
+            match self.get_component::<Parent>(current) {
+                Some(&Parent(parent)) => current = parent,
+                None => return false,
+            }
+        }
+    }
+
+    // Drops the entity from its parent's Children and orphans its own children.
+    pub fn detach(&mut self, entity: usize) {
+        if self.component_pool_exists::<Parent>() {
+            self.remove_parent(entity);
+        }
+        if !self.component_pool_exists::<Children>() {
+            return;
+        }
+
+        if let Some(Children(children)) = self.remove_component::<Children>(entity) {
+            for child in children {
+                self.remove_component::<Parent>(child);
+            }
+        }
+    }
+
+    pub fn despawn_recursive(&mut self, entity: impl EntityKey) -> bool {
+        let entity = match entity.resolve_in(self) {
+            Some(entity) => entity,
+            None => return false,
+        };
+        self.remove_parent(entity);
+
+        let mut stack = vec![entity];
+        while let Some(current) = stack.pop() {
+            if let Some(Children(children)) = self.remove_component::<Children>(current) {
+                stack.extend(children);
+            }
+
+            self.delete(current);
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::{
+        lineage::{Children, Parent},
+        Scene,
+    };
+
+    fn ship() -> (Scene, usize, [usize; 2], [usize; 2]) {
+        let mut scene = Scene::new();
+
+        let mut spawn = |value: i32| scene.spawn().with(value).id().raw_id();
+        let ship = spawn(0);
+        let turrets = [spawn(1), spawn(2)];
+        let barrels = [spawn(3), spawn(4)];
+
+        scene.set_parent(turrets[0], ship);
+        scene.set_parent(turrets[1], ship);
+        scene.set_parent(barrels[0], turrets[0]);
+        scene.set_parent(barrels[1], turrets[1]);
+
+        (scene, ship, turrets, barrels)
+    }
+
+    #[test]
+    fn setting_a_parent_updates_both_sides() {
+        let (scene, ship, turrets, _barrels) = ship();
+
+        assert_eq!(
+            scene.get_component::<Parent>(turrets[0]),
+            Some(&Parent(ship))
+        );
+        assert_eq!(
+            scene.get_component::<Children>(ship),
+            Some(&Children(vec![turrets[0], turrets[1]]))
+        );
+    }
+
+    #[test]
+    fn despawning_the_root_removes_every_descendant() {
+        let (mut scene, ship, _turrets, _barrels) = ship();
+        let stray = scene.spawn().with(5_i32).id();
+
+        scene.despawn_recursive(ship);
+
+        assert_eq!(scene.get::<i32>().iter().collect::<Vec<_>>(), vec![&5]);
+        assert_eq!(scene.component_count::<Parent>(), 0);
+        assert_eq!(scene.component_count::<Children>(), 0);
+        assert_eq!(
+            scene.entities().collect::<Vec<usize>>(),
+            vec![stray.raw_id()]
+        );
+    }
+
+    #[test]
+    fn despawning_a_subtree_detaches_it_from_its_parent() {
+        let (mut scene, ship, turrets, barrels) = ship();
+
+        scene.despawn_recursive(turrets[0]);
+
+        assert_eq!(
+            scene.get_component::<Children>(ship),
+            Some(&Children(vec![turrets[1]]))
+        );
+        assert!(!scene.has::<i32>(barrels[0]));
+        assert!(scene.has::<i32>(barrels[1]));
+    }
+
+    #[test]
+    fn reparenting_removes_the_child_from_its_old_parent() {
+        let (mut scene, _ship, turrets, barrels) = ship();
+
+        scene.set_parent(barrels[0], turrets[1]);
+
+        assert_eq!(
+            scene.get_component::<Children>(turrets[0]),
+            Some(&Children(vec![]))
+        );
+        assert_eq!(
+            scene.get_component::<Children>(turrets[1]),
+            Some(&Children(vec![barrels[1], barrels[0]]))
+        );
+        assert_eq!(
+            scene.get_component::<Parent>(barrels[0]),
+            Some(&Parent(turrets[1]))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Parenting entity 0 to 3 would create a cycle - Ignition")]
+    fn parenting_an_entity_to_its_descendant_panics() {
+        let (mut scene, ship, _turrets, barrels) = ship();
+
+        scene.set_parent(ship, barrels[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "would create a cycle")]
+    fn parenting_an_entity_to_itself_panics() {
+        let (mut scene, ship, _turrets, _barrels) = ship();
+
+        scene.set_parent(ship, ship);
+    }
+
+    #[test]
+    fn deleting_a_child_removes_it_from_its_parent() {
+        let (mut scene, ship, turrets, _barrels) = ship();
+
+        scene.delete(turrets[0]);
+
+        assert_eq!(
+            scene.get_component::<Children>(ship),
+            Some(&Children(vec![turrets[1]]))
+        );
+    }
+
+    #[test]
+    fn despawning_a_parent_spares_entities_that_reuse_a_deleted_child_id() {
+        let (mut scene, ship, turrets, _barrels) = ship();
+
+        scene.delete(turrets[0]);
+        let unrelated = scene.entity();
+        assert_eq!(unrelated.raw_id(), turrets[0]);
+
+        scene.despawn_recursive(ship);
+
+        assert!(scene.is_alive(unrelated));
+        assert!(!scene.is_live(turrets[1]));
+    }
+
+    #[test]
+    fn deleting_a_parent_orphans_its_children() {
+        let (mut scene, _ship, turrets, barrels) = ship();
+
+        scene.delete(turrets[0]);
+
+        assert_eq!(scene.get_component::<Parent>(barrels[0]), None);
+        assert!(scene.is_live(barrels[0]));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::life::{
+    genesis::entity::EntityKey, ghost::ComponentToggler, ComponentPool, ComponentPoolTrait, Entity,
+    Scene,
+};
+
+pub type ComponentCloner = fn(&mut dyn ComponentPoolTrait, usize, usize);
+
+impl Scene {
+    // Only pools registered through `register_cloneable` can be copied, the others are skipped with a warning.
+    pub fn duplicate(&mut self, entity: impl EntityKey) -> Option<Entity> {
+        let entity = entity.resolve_in(self)?;
+        let handle = self.entity();
+        let duplicate = handle.raw_id();
+
+        for (type_id, component_pool) in self.component_pools.iter_mut() {
+            if !component_pool.contains_entity(entity) {
+                continue;
+            }
+
+            match self.cloners.get(type_id) {
+                Some(cloner) => (cloner.component)(component_pool.as_mut(), entity, duplicate),
+                None => log::warn!(
+                    "Skipping component pool {:?} while duplicating entity {}, it was not registered as cloneable",
+                    type_id,
+                    entity
+                ),
+            }
+        }
+
+        Some(handle)
+    }
+}
+
+impl<G: 'static + Clone> ComponentPool<G> {
+    pub fn clone_component_into(&mut self, source: usize, destination: usize) {
+        if let Some(component) = self.try_get(source).cloned() {
+            let enabled = self.is_enabled(source);
+            self.assign_component(destination, component);
+
+            if !enabled && self.is_enabled(destination) {
+                self.disable_entity(destination);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::{ComponentPool, Scene};
+
+    #[derive(Debug, PartialEq)]
+    struct Brain(u32);
+
+    fn enemy() -> Scene {
+        let mut scene = Scene::new();
+        scene.register_cloneable::<i32>();
+        scene.register_cloneable::<String>();
+
+        let entity = scene.entity();
+        scene.component(entity, 100_i32);
+        scene.component(entity, String::from("goblin"));
+        scene.component(entity, Brain(7));
+
+        scene
+    }
+
+    #[test]
+    fn duplicating_copies_cloneable_components() {
+        let mut scene = enemy();
+
+        let duplicate = scene.duplicate(0).unwrap();
+
+        assert_eq!(duplicate.raw_id(), 1);
+        assert_eq!(scene.get_component::<i32>(duplicate), Some(&100));
+        assert_eq!(
+            scene.get_component::<String>(duplicate),
+            Some(&String::from("goblin"))
+        );
+    }
+
+    #[test]
+    fn duplicating_skips_components_that_are_not_cloneable() {
+        let mut scene = enemy();
+
+        let duplicate = scene.duplicate(0).unwrap();
+
+        assert_eq!(scene.get_component::<Brain>(0), Some(&Brain(7)));
+        assert_eq!(scene.get_component::<Brain>(duplicate), None);
+    }
+
+    #[test]
+    fn duplicates_are_independent_of_the_original() {
+        let mut scene = enemy();
+
+        let duplicate = scene.duplicate(0).unwrap();
+        *scene.get_component_mut::<i32>(duplicate).unwrap() = 50;
+
+        assert_eq!(scene.get_component::<i32>(0), Some(&100));
+        assert_eq!(scene.get_component::<i32>(duplicate), Some(&50));
+    }
+
+    #[test]
+    fn cloning_a_disabled_component_keeps_it_disabled() {
+        let mut pool = ComponentPool::new_with_entity(0, 3);
+        pool.assign_component(1, 5);
+        pool.num_components = 1;
+
+        pool.clone_component_into(1, 2);
+
+        assert_eq!(pool.component_array, vec![3, 5, 5]);
+        assert_eq!(pool.num_components, 1);
+        assert!(!pool.is_enabled(2));
+    }
+
+    #[test]
+    fn cloning_a_missing_component_does_nothing() {
+        let mut pool = ComponentPool::new_with_entity(0, 3);
+
+        pool.clone_component_into(4, 5);
+
+        assert_eq!(pool.component_array, vec![3]);
+        assert!(!pool.has_component(5));
+    }
+}
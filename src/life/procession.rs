@@ -0,0 +1,160 @@
+use crate::life::{genesis::entity::sparse_index, ComponentPool, Scene};
+
+impl Scene {
+    pub fn sort<G, K, F>(&mut self, key: F)
+    where
+        G: 'static,
+        K: Ord,
+        F: FnMut(&G) -> K,
+    {
+        if let Some(component_pool) = self.try_get_mut::<G>() {
+            component_pool.sort_by_key(key);
+        }
+    }
+
+    pub fn sort_by_entity<G: 'static>(&mut self) {
+        if let Some(component_pool) = self.try_get_mut::<G>() {
+            component_pool.sort_by_entity();
+        }
+    }
+}
+
+impl<G> ComponentPool<G> {
+    // Enabled and disabled components are sorted separately so the enabled ones stay in front.
+    // The sort is stable, so components with equal keys keep their relative order.
+    pub fn sort_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&G) -> K,
+    {
+        let keys: Vec<K> = self.component_array.iter().map(&mut key).collect();
+        self.reorder(|left, right| keys[*left].cmp(&keys[*right]));
+    }
+
+    pub fn sort_by_entity(&mut self) {
+        let entities = self.packed_array.clone();
+        self.reorder(|left, right| entities[*left].cmp(&entities[*right]));
+    }
+
+    fn reorder<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&usize, &usize) -> std::cmp::Ordering,
+    {
+        let mut order: Vec<usize> = (0..self.packed_array.len()).collect();
+        let (enabled, disabled) = order.split_at_mut(self.num_components);
+        enabled.sort_by(&mut compare);
+        disabled.sort_by(&mut compare);
+
+        let mut components: Vec<Option<G>> = self.component_array.drain(..).map(Some).collect();
+        self.component_array = order
+            .iter()
+            .map(|&index| components[index].take().unwrap())
+            .collect();
+        self.packed_array = order
+            .iter()
+            .map(|&index| self.packed_array[index])
+            .collect();
+
+        for (index, &entity) in self.packed_array.iter().enumerate() {
+            self.sparse_array[entity] = Some(sparse_index(index));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::{ComponentPool, Scene};
+
+    #[derive(Debug, PartialEq)]
+    struct Sprite {
+        z: i32,
+        name: &'static str,
+    }
+
+    fn scrambled_scene() -> Scene {
+        let mut scene = Scene::new();
+
+        for (z, name) in [
+            (3, "tree"),
+            (-1, "sky"),
+            (7, "player"),
+            (0, "ground"),
+            (3, "bush"),
+        ] {
+            let entity = scene.entity();
+            scene.component(entity, Sprite { z, name });
+        }
+        scene.remove_component::<Sprite>(1);
+        let entity = scene.entity();
+        scene.component(entity, Sprite { z: -1, name: "sky" });
+
+        scene
+    }
+
+    #[test]
+    fn sorting_by_a_key_orders_iteration() {
+        let mut scene = scrambled_scene();
+
+        scene.sort::<Sprite, _, _>(|sprite| sprite.z);
+
+        assert_eq!(
+            scene
+                .get::<Sprite>()
+                .iter()
+                .map(|sprite| sprite.name)
+                .collect::<Vec<_>>(),
+            vec!["sky", "ground", "tree", "bush", "player"]
+        );
+    }
+
+    #[test]
+    fn sorting_keeps_per_entity_lookups_intact() {
+        let mut scene = scrambled_scene();
+        let before: Vec<(usize, &'static str)> = scene
+            .iter_with_entities::<Sprite>()
+            .map(|(entity, sprite)| (entity, sprite.name))
+            .collect();
+
+        scene.sort::<Sprite, _, _>(|sprite| -sprite.z);
+
+        for (entity, name) in before {
+            assert_eq!(scene.get_component::<Sprite>(entity).unwrap().name, name);
+        }
+    }
+
+    #[test]
+    fn sorting_by_entity_restores_creation_order() {
+        let mut scene = scrambled_scene();
+
+        scene.sort::<Sprite, _, _>(|sprite| sprite.name);
+        scene.sort_by_entity::<Sprite>();
+
+        assert_eq!(scene.get::<Sprite>().packed_array, vec![0, 2, 3, 4, 5]);
+        assert_eq!(scene.get_component::<Sprite>(5).unwrap().name, "sky");
+    }
+
+    #[test]
+    fn sorting_keeps_disabled_components_behind_enabled_ones() {
+        let mut pool = ComponentPool::new_with_entity(0, 5);
+        pool.assign_component(1, 1);
+        pool.assign_component(2, 9);
+        pool.assign_component(3, 3);
+        pool.num_components = 2;
+
+        pool.sort_by_key(|value| *value);
+
+        assert_eq!(pool.component_array, vec![1, 5, 3, 9]);
+        assert_eq!(pool.packed_array, vec![1, 0, 3, 2]);
+        assert_eq!(pool.sparse_array, vec![Some(1), Some(0), Some(3), Some(2)]);
+        assert_eq!(pool.num_components, 2);
+    }
+
+    #[test]
+    fn sorting_a_missing_pool_does_nothing() {
+        let mut scene = Scene::new();
+
+        scene.sort::<Sprite, _, _>(|sprite| sprite.z);
+
+        assert!(!scene.component_pool_exists::<Sprite>());
+    }
+}
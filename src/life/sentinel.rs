@@ -0,0 +1,222 @@
+use std::any::{Any, TypeId};
+
+use crate::life::{ComponentPool, ComponentPoolTrait, Scene};
+
+pub type ComponentHook = Box<dyn FnMut(usize, &dyn Any)>;
+pub type ComponentFetcher = fn(&dyn ComponentPoolTrait, usize) -> Option<&dyn Any>;
+
+// Hooks only see the entity and its component, never the scene, so they cannot reach back into the pool
+// that is calling them. Changes to the scene have to go through state captured by the hook.
+pub struct ComponentHooks {
+    pub on_insert: Vec<ComponentHook>,
+    pub on_remove: Vec<ComponentHook>,
+    fetch: ComponentFetcher,
+}
+
+impl ComponentHooks {
+    fn new<G: 'static>() -> Self {
+        Self {
+            on_insert: Vec::new(),
+            on_remove: Vec::new(),
+            fetch: fetch_component::<G>,
+        }
+    }
+}
+
+impl Scene {
+    pub fn on_insert<G, F>(&mut self, mut callback: F)
+    where
+        G: 'static,
+        F: 'static + FnMut(usize, &G),
+    {
+        self.hooks_for::<G>()
+            .on_insert
+            .push(Box::new(move |entity, component| {
+                callback(entity, component.downcast_ref::<G>().unwrap())
+            }));
+    }
+
+    pub fn on_remove<G, F>(&mut self, mut callback: F)
+    where
+        G: 'static,
+        F: 'static + FnMut(usize, &G),
+    {
+        self.hooks_for::<G>()
+            .on_remove
+            .push(Box::new(move |entity, component| {
+                callback(entity, component.downcast_ref::<G>().unwrap())
+            }));
+    }
+
+    pub fn run_insert_hooks<G: 'static>(&mut self, entity: usize) {
+        self.run_hooks(TypeId::of::<G>(), entity, |hooks| &mut hooks.on_insert);
+    }
+
+    // Runs while the component is still in its pool, right before it goes away.
+    pub fn run_remove_hooks(&mut self, type_id: TypeId, entity: usize) {
+        self.run_hooks(type_id, entity, |hooks| &mut hooks.on_remove);
+    }
+
+    pub fn run_remove_hooks_for_every_entity(&mut self, type_id: TypeId) {
+        let entities = match self.component_pools.get(&type_id) {
+            Some(component_pool) if self.hooks.contains_key(&type_id) => {
+                component_pool.entities().to_vec()
+            }
+            _ => return,
+        };
+
+        for entity in entities {
+            self.run_remove_hooks(type_id, entity);
+        }
+    }
+
+    fn run_hooks<S>(&mut self, type_id: TypeId, entity: usize, select: S)
+    where
+        S: FnOnce(&mut ComponentHooks) -> &mut Vec<ComponentHook>,
+    {
+        if self.hooks.is_empty() {
+            return;
+        }
+        let hooks = match self.hooks.get_mut(&type_id) {
+            Some(hooks) => hooks,
+            None => return,
+        };
+        let fetch = hooks.fetch;
+        let component = match self
+            .component_pools
+            .get(&type_id)
+            .and_then(|component_pool| fetch(component_pool, entity))
+        {
+            Some(component) => component,
+            None => return,
+        };
+
+        for hook in select(hooks).iter_mut() {
+            hook(entity, component);
+        }
+    }
+
+    fn hooks_for<G: 'static>(&mut self) -> &mut ComponentHooks {
+        self.hooks
+            .entry(TypeId::of::<G>())
+            .or_insert_with(ComponentHooks::new::<G>)
+    }
+}
+
+fn fetch_component<G: 'static>(
+    component_pool: &dyn ComponentPoolTrait,
+    entity: usize,
+) -> Option<&dyn Any> {
+    component_pool
+        .as_any()
+        .downcast_ref::<ComponentPool<G>>()?
+        .try_get(entity)
+        .map(|component| component as &dyn Any)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::life::Scene;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Counts {
+        inserted: Vec<(usize, i32)>,
+        removed: Vec<(usize, i32)>,
+    }
+
+    fn counted() -> (Scene, Rc<RefCell<Counts>>) {
+        let mut scene = Scene::new();
+        let counts = Rc::new(RefCell::new(Counts::default()));
+
+        let inserted = counts.clone();
+        scene.on_insert::<i32, _>(move |entity, component| {
+            inserted.borrow_mut().inserted.push((entity, *component))
+        });
+        let removed = counts.clone();
+        scene.on_remove::<i32, _>(move |entity, component| {
+            removed.borrow_mut().removed.push((entity, *component))
+        });
+
+        (scene, counts)
+    }
+
+    #[test]
+    fn hooks_run_on_insert_overwrite_and_remove() {
+        let (mut scene, counts) = counted();
+        let entity = scene.entity();
+
+        scene.component(entity, 1_i32);
+        scene.component(entity, 2_i32);
+        scene.remove_component::<i32>(entity);
+
+        assert_eq!(
+            *counts.borrow(),
+            Counts {
+                inserted: vec![(entity.raw_id(), 1), (entity.raw_id(), 2)],
+                removed: vec![(entity.raw_id(), 1), (entity.raw_id(), 2)],
+            }
+        );
+    }
+
+    #[test]
+    fn deleting_an_entity_runs_remove_hooks() {
+        let (mut scene, counts) = counted();
+        let first = scene.entity();
+        let second = scene.entity();
+        scene.component(first, 1_i32);
+        scene.component(second, 2_i32);
+        scene.component(second, 0.5_f32);
+
+        scene.delete(second);
+        scene.delete(second);
+        scene.clear_components::<i32>();
+
+        assert_eq!(
+            counts.borrow().removed,
+            vec![(second.raw_id(), 2), (first.raw_id(), 1)]
+        );
+    }
+
+    #[test]
+    fn batches_and_taken_components_run_hooks() {
+        let (mut scene, counts) = counted();
+
+        let entities = scene.spawn_batch(vec![4_i32, 5]);
+        scene.take_component::<i32>(entities[0]);
+
+        assert_eq!(counts.borrow().inserted, vec![(0, 4), (1, 5)]);
+        assert_eq!(counts.borrow().removed, vec![(0, 4)]);
+    }
+
+    #[test]
+    fn hooks_for_one_type_run_in_registration_order() {
+        let mut scene = Scene::new();
+        let order = Rc::new(RefCell::new(Vec::new()));
+
+        for name in ["first", "second", "third"] {
+            let order = order.clone();
+            scene.on_insert::<u8, _>(move |_entity, _component| order.borrow_mut().push(name));
+        }
+        let entity = scene.entity();
+        scene.component(entity, 1_u8);
+
+        assert_eq!(*order.borrow(), vec!["first", "second", "third"]);
+    }
+
+    #[test]
+    fn clearing_the_scene_runs_remove_hooks_and_keeps_them() {
+        let (mut scene, counts) = counted();
+        let entity = scene.entity();
+        scene.component(entity, 3_i32);
+
+        scene.clear();
+        let entity = scene.entity();
+        scene.component(entity, 4_i32);
+
+        assert_eq!(counts.borrow().removed, vec![(0, 3)]);
+        assert_eq!(counts.borrow().inserted, vec![(0, 3), (0, 4)]);
+    }
+}
@@ -0,0 +1,94 @@
+use rayon::prelude::*;
+
+use crate::life::{ComponentPool, Scene};
+
+impl Scene {
+    pub fn par_get<G: 'static + Send + Sync>(&self) -> impl ParallelIterator<Item = &G> {
+        self.get::<G>().par_iter()
+    }
+
+    pub fn par_get_mut<G: 'static + Send + Sync>(
+        &mut self,
+    ) -> impl ParallelIterator<Item = &mut G> {
+        self.get_mut::<G>().par_iter_mut()
+    }
+}
+
+impl<G: Send + Sync> ComponentPool<G> {
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &G> {
+        self.component_array[..self.num_components].par_iter()
+    }
+
+    // Workers cannot share the flags, so every enabled component is marked up front.
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut G> {
+        for index in 0..self.num_components {
+            let entity = self.packed_array[index];
+            self.mark_changed(entity);
+        }
+
+        self.component_array[..self.num_components].par_iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use crate::life::Scene;
+
+    fn scene_with_components(count: usize) -> Scene {
+        let mut scene = Scene::new();
+
+        for value in 0..count {
+            let entity = scene.entity();
+            scene.component(entity, value as u64);
+        }
+
+        scene
+    }
+
+    #[test]
+    fn mutating_components_in_parallel_matches_sequential_results() {
+        let mut parallel_scene = scene_with_components(100_000);
+        let mut sequential_scene = scene_with_components(100_000);
+
+        parallel_scene
+            .par_get_mut::<u64>()
+            .for_each(|value| *value = *value * 3 + 1);
+        sequential_scene
+            .get_mut::<u64>()
+            .iter_mut()
+            .for_each(|value| *value = *value * 3 + 1);
+
+        assert_eq!(
+            parallel_scene.get::<u64>().component_array,
+            sequential_scene.get::<u64>().component_array
+        );
+        assert_eq!(
+            parallel_scene.par_get::<u64>().sum::<u64>(),
+            sequential_scene.get::<u64>().iter().sum::<u64>()
+        );
+    }
+
+    #[test]
+    fn parallel_iteration_skips_disabled_components() {
+        let mut scene = scene_with_components(4);
+
+        scene.disable::<u64>(1);
+        scene.par_get_mut::<u64>().for_each(|value| *value += 10);
+
+        assert_eq!(scene.par_get::<u64>().count(), 3);
+        assert_eq!(scene.get_component::<u64>(1), Some(&1));
+    }
+
+    #[test]
+    fn parallel_mutation_marks_every_enabled_component() {
+        let mut scene = scene_with_components(4);
+        scene.drain_changed::<u64>();
+
+        scene.disable::<u64>(1);
+        scene.par_get_mut::<u64>().for_each(|value| *value += 10);
+
+        assert_eq!(scene.changed::<u64>(), vec![0, 2, 3]);
+    }
+}
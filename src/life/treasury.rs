@@ -0,0 +1,114 @@
+use std::any::{type_name, Any, TypeId};
+
+use crate::life::Scene;
+
+pub type Resource = Box<dyn Any>;
+
+impl Scene {
+    pub fn insert_resource<R: 'static>(&mut self, resource: R) -> Option<R> {
+        self.resources
+            .insert(TypeId::of::<R>(), Box::new(resource))
+            .and_then(|previous| previous.downcast::<R>().ok())
+            .map(|previous| *previous)
+    }
+
+    pub fn resource<R: 'static>(&self) -> &R {
+        self.try_resource::<R>()
+            .unwrap_or_else(|| missing_resource::<R>())
+    }
+
+    pub fn try_resource<R: 'static>(&self) -> Option<&R> {
+        self.resources.get(&TypeId::of::<R>())?.downcast_ref::<R>()
+    }
+
+    pub fn resource_mut<R: 'static>(&mut self) -> &mut R {
+        self.try_resource_mut::<R>()
+            .unwrap_or_else(|| missing_resource::<R>())
+    }
+
+    pub fn try_resource_mut<R: 'static>(&mut self) -> Option<&mut R> {
+        self.resources
+            .get_mut(&TypeId::of::<R>())?
+            .downcast_mut::<R>()
+    }
+
+    pub fn remove_resource<R: 'static>(&mut self) -> Option<R> {
+        self.resources
+            .remove(&TypeId::of::<R>())
+            .and_then(|resource| resource.downcast::<R>().ok())
+            .map(|resource| *resource)
+    }
+
+    pub fn resource_exists<R: 'static>(&self) -> bool {
+        self.resources.contains_key(&TypeId::of::<R>())
+    }
+}
+
+fn missing_resource<R>() -> ! {
+    panic!(
+        "Error: No resource exists for {} - Ignition",
+        type_name::<R>()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life::Scene;
+
+    #[derive(Debug, PartialEq)]
+    struct Camera {
+        x: f32,
+        y: f32,
+    }
+
+    #[test]
+    fn mutating_a_resource_is_visible_when_read_back() {
+        let mut scene = Scene::new();
+
+        scene.insert_resource(Camera { x: 0.0, y: 0.0 });
+        scene.resource_mut::<Camera>().x = 2.5;
+
+        assert_eq!(scene.resource::<Camera>(), &Camera { x: 2.5, y: 0.0 });
+    }
+
+    #[test]
+    fn inserting_a_resource_twice_replaces_it() {
+        let mut scene = Scene::new();
+
+        scene.insert_resource(32_i32);
+        let previous = scene.insert_resource(21_i32);
+
+        assert_eq!(previous, Some(32));
+        assert_eq!(scene.resource::<i32>(), &21);
+    }
+
+    #[test]
+    fn removing_a_resource_returns_it() {
+        let mut scene = Scene::new();
+
+        scene.insert_resource(Camera { x: 1.0, y: 1.0 });
+
+        assert_eq!(
+            scene.remove_resource::<Camera>(),
+            Some(Camera { x: 1.0, y: 1.0 })
+        );
+        assert!(!scene.resource_exists::<Camera>());
+    }
+
+    #[test]
+    fn requesting_a_missing_resource_returns_none() {
+        let mut scene = Scene::new();
+
+        assert_eq!(scene.try_resource::<Camera>(), None);
+        assert_eq!(scene.try_resource_mut::<Camera>(), None);
+        assert_eq!(scene.remove_resource::<Camera>(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: No resource exists for")]
+    fn requesting_a_missing_resource_panics() {
+        let scene = Scene::new();
+
+        scene.resource::<Camera>();
+    }
+}
@@ -1,17 +1,39 @@
-use wgpu::{Adapter, Backends, Device, Instance, Queue, Surface, SurfaceConfiguration};
+use std::any::Any;
+
+use wgpu::{
+    Adapter, Backends, BindGroupLayout, BufferUsages, Device, Instance, Queue, Surface,
+    SurfaceConfiguration, Texture,
+};
 use winit::{dpi::PhysicalSize, event_loop::EventLoop, window::Window};
 
 pub mod apex;
 pub mod artist;
+pub mod easel;
+pub mod lattice;
+pub mod lens;
 pub mod life;
 pub mod lift_off;
+pub mod metamorphosis;
 pub mod nostalgia;
+pub mod pantry;
+pub mod posture;
+pub mod purgatory;
+pub mod scripture;
+pub mod sculpture;
 pub mod silhouette;
 
 use crate::{
     liberty::Parameters,
-    manifestation::lift_off::{
-        create_surface, create_window, generate_default_configuration, get_adapter, get_device,
+    manifestation::{
+        lattice::GridPass,
+        lens::CameraBinding,
+        lift_off::{
+            create_surface, create_window, generate_default_configuration, get_adapter, get_device,
+        },
+        nostalgia::{create_depth_texture, create_multisample_texture},
+        pantry::BufferArena,
+        posture::model_layout,
+        purgatory::{Graveyard, ReleaseQueue, FRAMES_IN_FLIGHT},
     },
 };
 
@@ -22,11 +44,24 @@ pub struct Renderer {
 
     pub surface: Surface,
     pub config: SurfaceConfiguration,
+    pub depth_texture: Texture,
+    pub multisample_texture: Option<Texture>,
+    pub sample_count: u32,
 
     pub adapter: Adapter,
 
     pub device: Device,
     pub queue: Queue,
+
+    pub camera: CameraBinding,
+    pub model_layout: BindGroupLayout,
+    pub grid: Option<GridPass>,
+
+    pub vertex_arena: BufferArena,
+    pub index_arena: BufferArena,
+
+    pub release_queue: ReleaseQueue<Box<dyn Any>>,
+    pub graveyard: Graveyard,
 }
 
 impl Renderer {
@@ -40,8 +75,15 @@ impl Renderer {
         println!("Device name : {}", adapter.get_info().name);
         let (device, queue) = pollster::block_on(get_device(&adapter));
 
-        let config = generate_default_configuration(&size, &surface, &adapter);
+        let config = generate_default_configuration(&size, &surface, &adapter, parameters.vsync);
         surface.configure(&device, &config);
+        let sample_count = parameters.sample_count;
+        let depth_texture = create_depth_texture(&device, &config, sample_count);
+        let multisample_texture = create_multisample_texture(&device, &config, sample_count);
+        let camera = CameraBinding::new(&device);
+        let model_layout = model_layout(&device);
+        let vertex_arena = BufferArena::new(&device, BufferUsages::VERTEX, "Vertex Arena");
+        let index_arena = BufferArena::new(&device, BufferUsages::INDEX, "Index Arena");
 
         Self {
             event_loop: Some(event_loop),
@@ -49,10 +91,23 @@ impl Renderer {
             size,
             surface,
             config,
+            depth_texture,
+            multisample_texture,
+            sample_count,
 
             adapter,
             device,
             queue,
+
+            camera,
+            model_layout,
+            grid: None,
+
+            vertex_arena,
+            index_arena,
+
+            release_queue: ReleaseQueue::new(FRAMES_IN_FLIGHT),
+            graveyard: Graveyard::default(),
         }
     }
 }
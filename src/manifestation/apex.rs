@@ -1,4 +1,6 @@
-use wgpu::{BufferAddress, VertexAttribute, VertexBufferLayout, VertexFormat, VertexStepMode};
+use wgpu::{
+    BufferAddress, IndexFormat, VertexAttribute, VertexBufferLayout, VertexFormat, VertexStepMode,
+};
 
 #[derive(Debug)]
 pub struct VertexGroup {
@@ -28,6 +30,22 @@ impl VertexGroup {
         step: usize,
         format: VertexFormat,
     ) {
+        self.data_slice(&data, step, format);
+    }
+
+    pub fn data_slice<G: serde::ser::Serialize>(
+        &mut self,
+        data: &[G],
+        step: usize,
+        format: VertexFormat,
+    ) {
+        debug_assert!(
+            self.data.is_empty() || data.len() / step == self.num_vertices as usize,
+            "Error: Vertex attribute has {} values for {} vertices - Ignition",
+            data.len() / step,
+            self.num_vertices
+        );
+
         self.layout.push(VertexAttribute {
             offset: self.stride as BufferAddress,
             shader_location: self.shader_location,
@@ -43,7 +61,7 @@ impl VertexGroup {
                 self.data.push(Vec::with_capacity(step))
             }
 
-            for value in point.into_iter() {
+            for value in point.iter() {
                 self.data[i].push(bincode::serialize(value).unwrap());
             }
         }
@@ -53,7 +71,45 @@ impl VertexGroup {
         self.data.concat().concat()
     }
 
-    pub fn layout(&self) -> VertexBufferLayout {
+    // Reads the first attribute as the position, an empty group gives a box collapsed at the origin.
+    // Positions that are not floats give None, culling should leave those shapes alone.
+    pub fn aabb(&self) -> Option<([f32; 2], [f32; 2])> {
+        if self.data.is_empty() {
+            return Some(([0.0; 2], [0.0; 2]));
+        }
+        if !matches!(
+            self.layout.first()?.format,
+            VertexFormat::Float32x2 | VertexFormat::Float32x3 | VertexFormat::Float32x4
+        ) {
+            return None;
+        }
+
+        let mut min = [f32::INFINITY; 2];
+        let mut max = [f32::NEG_INFINITY; 2];
+        for vertex in &self.data {
+            for axis in 0..2 {
+                let value = position_value(vertex.get(axis)?)?;
+                min[axis] = min[axis].min(value);
+                max[axis] = max[axis].max(value);
+            }
+        }
+
+        Some((min, max))
+    }
+
+    // Enough to build a pipeline from, without keeping the vertex data around.
+    pub fn layout_only(&self) -> VertexGroup {
+        VertexGroup {
+            data: Vec::new(),
+
+            stride: self.stride,
+            num_vertices: self.num_vertices,
+            shader_location: self.shader_location,
+            layout: self.layout.clone(),
+        }
+    }
+
+    pub fn layout(&self) -> VertexBufferLayout<'_> {
         VertexBufferLayout {
             array_stride: self.stride as BufferAddress,
             step_mode: VertexStepMode::Vertex,
@@ -62,11 +118,76 @@ impl VertexGroup {
     }
 }
 
+// Positions may have been given as f32 or f64, the serialized width tells them apart.
+fn position_value(bytes: &[u8]) -> Option<f32> {
+    match bytes.len() {
+        4 => bincode::deserialize::<f32>(bytes).ok(),
+        8 => bincode::deserialize::<f64>(bytes)
+            .ok()
+            .map(|value| value as f32),
+        _ => None,
+    }
+}
+
+impl Default for VertexGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexData {
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+}
+
+impl IndexData {
+    pub fn format(&self) -> IndexFormat {
+        match self {
+            IndexData::U16(_) => IndexFormat::Uint16,
+            IndexData::U32(_) => IndexFormat::Uint32,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            IndexData::U16(indices) => indices.len(),
+            IndexData::U32(indices) => indices.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self) -> &[u8] {
+        match self {
+            IndexData::U16(indices) => bytemuck::cast_slice(indices),
+            IndexData::U32(indices) => bytemuck::cast_slice(indices),
+        }
+    }
+}
+
+impl From<Vec<u16>> for IndexData {
+    fn from(indices: Vec<u16>) -> Self {
+        IndexData::U16(indices)
+    }
+}
+
+impl From<Vec<u32>> for IndexData {
+    fn from(indices: Vec<u32>) -> Self {
+        IndexData::U32(indices)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use wgpu::{BufferAddress, VertexAttribute, VertexBufferLayout, VertexFormat, VertexStepMode};
+    use wgpu::{
+        BufferAddress, IndexFormat, VertexAttribute, VertexBufferLayout, VertexFormat,
+        VertexStepMode,
+    };
 
-    use crate::manifestation::apex::VertexGroup;
+    use crate::manifestation::apex::{IndexData, VertexGroup};
 
     #[test]
     fn vertex_data_gets_seperated_and_casted_correctly() {
@@ -142,21 +263,13 @@ mod tests {
     fn layout_is_generated_correctly() {
         let mut vertex_group = VertexGroup::new();
         vertex_group.data(
-            [
-                0.55 as f32,
-                -0.5 as f32,
-                0.55 as f32,
-                0.55 as f32,
-                -0.5 as f32,
-                0.55 as f32,
-            ],
+            [0.55_f32, -0.5_f32, 0.55_f32, 0.55_f32, -0.5_f32, 0.55_f32],
             2,
             VertexFormat::Float32x2,
         );
         vertex_group.data(
             [
-                1.0 as f32, 0.0 as f32, 0.0 as f32, 0.0 as f32, 1.0 as f32, 0.0 as f32, 0.0 as f32,
-                0.0 as f32, 1.0 as f32,
+                1.0_f32, 0.0_f32, 0.0_f32, 0.0_f32, 1.0_f32, 0.0_f32, 0.0_f32, 0.0_f32, 1.0_f32,
             ],
             3,
             VertexFormat::Float32x3,
@@ -208,4 +321,102 @@ mod tests {
             [0.55, -0.5, 1.0, 0.0, 0.0, 0.55, 0.55, 0.0, 1.0, 0.0, -0.5, 0.55, 0.0, 0.0, 1.0],
         )
     }
+
+    #[test]
+    fn bounding_box_spans_the_positions() {
+        let mut vertex_group = VertexGroup::new();
+        vertex_group.data(
+            [0.55, -0.5, 0.25, 0.75, -0.5, 0.55],
+            2,
+            VertexFormat::Float32x2,
+        );
+        vertex_group.data(
+            [9.0_f32, 9.0, 9.0, -9.0, -9.0, -9.0],
+            2,
+            VertexFormat::Float32x2,
+        );
+
+        assert_eq!(vertex_group.aabb(), Some(([-0.5, -0.5], [0.55, 0.75])));
+    }
+
+    #[test]
+    fn bounding_box_reads_f32_positions_with_a_depth() {
+        let mut vertex_group = VertexGroup::new();
+        vertex_group.data(
+            [1.0_f32, 2.0, 5.0, -3.0, 4.0, -5.0],
+            3,
+            VertexFormat::Float32x3,
+        );
+
+        assert_eq!(vertex_group.aabb(), Some(([-3.0, 2.0], [1.0, 4.0])));
+    }
+
+    #[test]
+    fn empty_vertex_group_has_a_collapsed_bounding_box() {
+        assert_eq!(VertexGroup::new().aabb(), Some(([0.0, 0.0], [0.0, 0.0])));
+    }
+
+    #[test]
+    fn positions_that_are_not_floats_have_no_bounding_box() {
+        let mut vertex_group = VertexGroup::new();
+        vertex_group.data([1_u32, 2], 2, VertexFormat::Uint32x2);
+
+        assert_eq!(vertex_group.aabb(), None);
+    }
+
+    #[test]
+    fn positions_of_an_unexpected_width_have_no_bounding_box() {
+        let mut vertex_group = VertexGroup::new();
+        vertex_group.data([1_u16, 2], 2, VertexFormat::Float32x2);
+
+        assert_eq!(vertex_group.aabb(), None);
+    }
+
+    #[test]
+    fn u16_indices_use_uint16_format() {
+        let indices = IndexData::from(vec![0_u16, 1, 2, 2, 3, 0]);
+
+        assert_eq!(indices.format(), IndexFormat::Uint16);
+        assert_eq!(indices.len(), 6);
+        assert_eq!(indices.get().len(), 6 * std::mem::size_of::<u16>());
+    }
+
+    #[test]
+    fn u32_indices_use_uint32_format() {
+        let indices = IndexData::from(vec![0_u32, 70_000, 70_001]);
+
+        assert_eq!(indices.format(), IndexFormat::Uint32);
+        assert_eq!(indices.len(), 3);
+        assert_eq!(
+            indices.get(),
+            bytemuck::cast_slice::<u32, u8>(&[0, 70_000, 70_001])
+        );
+    }
+
+    #[test]
+    fn slice_vertex_data_matches_array_vertex_data() {
+        let mut from_array = VertexGroup::new();
+        from_array.data([0.55, -0.5, 0.55, 0.55], 2, VertexFormat::Float32x2);
+        from_array.data([1.0, 0.0, 0.0, 0.0, 1.0, 0.0], 3, VertexFormat::Float32x3);
+
+        let mut from_slice = VertexGroup::new();
+        from_slice.data_slice(&[0.55, -0.5, 0.55, 0.55], 2, VertexFormat::Float32x2);
+        from_slice.data_slice(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0], 3, VertexFormat::Float32x3);
+
+        assert_eq!(from_array.get(), from_slice.get());
+        assert_eq!(from_array.num_vertices, from_slice.num_vertices);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Error: Vertex attribute has 2 values for 3 vertices - Ignition")]
+    fn mismatched_vertex_attribute_lengths_panic_in_debug() {
+        let mut vertex_group = VertexGroup::new();
+        vertex_group.data(
+            [0.55, -0.5, 0.55, 0.55, -0.5, 0.55],
+            2,
+            VertexFormat::Float32x2,
+        );
+        vertex_group.data([1.0, 0.0, 0.0, 0.0, 1.0, 0.0], 3, VertexFormat::Float32x3);
+    }
 }
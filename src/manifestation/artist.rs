@@ -1,23 +1,35 @@
 pub mod command_buffer;
 pub mod pipeline;
+use std::{mem, time::Instant};
+
 use wgpu::RenderPass;
 
 use winit::{
     dpi::PhysicalSize,
-    event::{Event, WindowEvent},
+    event::{Event, KeyboardInput, WindowEvent},
     event_loop::ControlFlow,
 };
 
 use crate::{
-    manifestation::{artist::command_buffer::Commands, silhouette::Renderable},
+    manifestation::{
+        artist::command_buffer::Commands, easel::RenderContext, silhouette::Renderable,
+    },
     Engine,
 };
 
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum LoopControl {
+    Continue,
+    Exit,
+}
+
 impl Engine {
     pub fn game_loop<F>(mut self, mut closure: F)
     where
-        F: 'static + FnMut(&mut Engine),
+        F: 'static + FnMut(&mut Engine) -> LoopControl,
     {
+        self.last_frame_instant = Instant::now();
+
         self.renderer
             .event_loop
             .take()
@@ -33,24 +45,61 @@ impl Engine {
                         self.resize(size);
                     }
 
+                    Event::WindowEvent {
+                        event: WindowEvent::ScaleFactorChanged { new_inner_size, .. },
+                        ..
+                    } => {
+                        self.resize(*new_inner_size);
+                    }
+
                     Event::WindowEvent {
                         event: WindowEvent::CloseRequested,
                         ..
                     } => *control_flow = ControlFlow::Exit,
 
+                    Event::WindowEvent {
+                        event:
+                            WindowEvent::KeyboardInput {
+                                input:
+                                    KeyboardInput {
+                                        virtual_keycode: Some(key),
+                                        state,
+                                        ..
+                                    },
+                                ..
+                            },
+                        ..
+                    } => self.input.keyboard_input(key, state),
+
+                    Event::WindowEvent {
+                        event: WindowEvent::CursorMoved { position, .. },
+                        ..
+                    } => self.input.cursor_moved(position, self.renderer.size),
+
+                    Event::WindowEvent {
+                        event: WindowEvent::MouseInput { button, state, .. },
+                        ..
+                    } => self.input.mouse_input(button, state),
+
                     Event::RedrawRequested(_) => {
+                        self.reload_shaders();
+                        self.update_camera();
+                        self.update_transforms();
+                        self.update_batches();
+                        self.update_grid();
+
                         let mut commands = match Commands::ignite(&self) {
                             Ok(commands) => commands,
-                            Err(wgpu::SurfaceError::Lost) => {
+                            Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
                                 self.resize(self.renderer.size);
                                 return;
                             }
-                            Err(wgpu::SurfaceError::OutOfMemory) => {
-                                *control_flow = ControlFlow::Exit;
+                            Err(wgpu::SurfaceError::Timeout) => {
+                                log::warn!("Skipping frame, the surface timed out");
                                 return;
                             }
-                            Err(e) => {
-                                eprintln!("{:?}", e);
+                            Err(wgpu::SurfaceError::OutOfMemory) => {
+                                *control_flow = ControlFlow::Exit;
                                 return;
                             }
                         };
@@ -62,24 +111,62 @@ impl Engine {
                         }
 
                         commands.execute(&self);
+                        self.renderer.advance_release_queue();
                     }
 
                     Event::MainEventsCleared => {
-                        closure(&mut self);
+                        let delta = self.advance_frame(Instant::now());
+
+                        self.run_scene_systems(delta);
+                        self.run_alarms(delta);
+                        let loop_control = closure(&mut self);
+                        self.scene.apply_commands();
+                        self.scene.clear_events();
+                        self.input.refresh();
+
+                        if loop_control == LoopControl::Exit {
+                            *control_flow = ControlFlow::Exit;
+                            return;
+                        }
 
                         self.renderer.window.request_redraw();
                     }
+
+                    // Both the close button and LoopControl::Exit end up here.
+                    Event::LoopDestroyed => {
+                        let drained = self.shutdown();
+
+                        // Dropping the renderer could hang on a wedged driver, so leave without it and
+                        // report the failure through the exit code.
+                        if !drained {
+                            log::error!("Exiting without releasing the GPU, it stopped responding");
+                            std::process::exit(1);
+                        }
+                    }
                     _ => {}
                 }
             });
     }
 
     pub fn render<'a>(&'a mut self, render_pass: &mut RenderPass<'a>) {
-        if self.scene.component_pool_exists::<Box<dyn Renderable>>() {
-            let shapes = self.scene.get::<Box<dyn Renderable>>();
+        if let Some(grid) = self
+            .renderer
+            .grid
+            .as_ref()
+            .filter(|grid| grid.settings.is_drawn())
+        {
+            grid.render(render_pass);
+        }
+
+        render_pass.set_bind_group(0, &self.renderer.camera.bind_group, &[]);
 
-            for shape in shapes.iter() {
-                shape.render(render_pass);
+        for scene in self.rendered_scenes() {
+            if let Some(shapes) = scene.try_get::<Box<dyn Renderable>>() {
+                let context = RenderContext::new(&self.renderer, scene);
+
+                for shape in shapes.iter() {
+                    shape.render(render_pass, &context);
+                }
             }
         }
     }
@@ -99,5 +186,13 @@ impl Engine {
         self.renderer
             .surface
             .configure(&self.renderer.device, &self.renderer.config);
+
+        let depth_texture = self.renderer.depth_texture();
+        let old = mem::replace(&mut self.renderer.depth_texture, depth_texture);
+        self.renderer.release(old);
+
+        let multisample_texture = self.renderer.multisample_texture();
+        let old = mem::replace(&mut self.renderer.multisample_texture, multisample_texture);
+        self.renderer.release(old);
     }
 }
@@ -1,6 +1,7 @@
 use wgpu::{
     Color, CommandEncoder, CommandEncoderDescriptor, LoadOp, Operations, RenderPass,
-    RenderPassDescriptor, SurfaceError, SurfaceTexture, TextureView, TextureViewDescriptor,
+    RenderPassDepthStencilAttachment, RenderPassDescriptor, SurfaceError, SurfaceTexture,
+    TextureView, TextureViewDescriptor,
 };
 
 use crate::Engine;
@@ -8,6 +9,8 @@ use crate::Engine;
 pub struct Commands {
     frame: SurfaceTexture,
     view: TextureView,
+    multisample_view: Option<TextureView>,
+    depth_view: TextureView,
 
     encoder: CommandEncoder,
 }
@@ -16,19 +19,28 @@ impl Commands {
     pub fn ignite(engine: &Engine) -> Result<Self, SurfaceError> {
         let frame = create_frame(engine)?;
         let view = create_view(&frame);
+        let multisample_view = create_multisample_view(engine);
+        let depth_view = create_depth_view(engine);
 
         let encoder = create_command_encoder(engine);
 
         Ok(Self {
             frame,
             view,
+            multisample_view,
+            depth_view,
 
             encoder,
         })
     }
 
-    pub fn ignite_render_pass(&mut self) -> RenderPass {
-        create_render_pass(&mut self.encoder, &self.view)
+    pub fn ignite_render_pass(&mut self) -> RenderPass<'_> {
+        create_render_pass(
+            &mut self.encoder,
+            &self.view,
+            self.multisample_view.as_ref(),
+            &self.depth_view,
+        )
     }
 
     pub fn execute(self, engine: &Engine) {
@@ -40,19 +52,28 @@ impl Commands {
 }
 
 pub fn create_frame(engine: &Engine) -> Result<SurfaceTexture, SurfaceError> {
-    let frame = engine
-        .renderer
-        .surface
-        .get_current_texture()
-        .expect("Failed to acquire next swap chain texture");
-
-    Ok(frame)
+    engine.renderer.surface.get_current_texture()
 }
 
 pub fn create_view(frame: &SurfaceTexture) -> TextureView {
     frame.texture.create_view(&TextureViewDescriptor::default())
 }
 
+pub fn create_multisample_view(engine: &Engine) -> Option<TextureView> {
+    engine
+        .renderer
+        .multisample_texture
+        .as_ref()
+        .map(|texture| texture.create_view(&TextureViewDescriptor::default()))
+}
+
+pub fn create_depth_view(engine: &Engine) -> TextureView {
+    engine
+        .renderer
+        .depth_texture
+        .create_view(&TextureViewDescriptor::default())
+}
+
 pub fn create_command_encoder(engine: &Engine) -> CommandEncoder {
     engine
         .renderer
@@ -60,15 +81,27 @@ pub fn create_command_encoder(engine: &Engine) -> CommandEncoder {
         .create_command_encoder(&CommandEncoderDescriptor { label: None })
 }
 
+// The attachment drawn into and, when multisampling, the surface it resolves onto.
+pub fn color_targets<T>(view: T, multisample_view: Option<T>) -> (T, Option<T>) {
+    match multisample_view {
+        Some(multisample_view) => (multisample_view, Some(view)),
+        None => (view, None),
+    }
+}
+
 pub fn create_render_pass<'a>(
     encoder: &'a mut CommandEncoder,
     view: &'a TextureView,
+    multisample_view: Option<&'a TextureView>,
+    depth_view: &'a TextureView,
 ) -> RenderPass<'a> {
+    let (view, resolve_target) = color_targets(view, multisample_view);
+
     encoder.begin_render_pass(&RenderPassDescriptor {
         label: None,
         color_attachments: &[Some(wgpu::RenderPassColorAttachment {
             view,
-            resolve_target: None,
+            resolve_target,
             ops: Operations {
                 load: LoadOp::Clear(Color {
                     r: 0.0,
@@ -79,6 +112,31 @@ pub fn create_render_pass<'a>(
                 store: true,
             },
         })],
-        depth_stencil_attachment: None,
+        depth_stencil_attachment: Some(RenderPassDepthStencilAttachment {
+            view: depth_view,
+            depth_ops: Some(Operations {
+                load: LoadOp::Clear(1.0),
+                store: true,
+            }),
+            stencil_ops: None,
+        }),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::manifestation::artist::command_buffer::color_targets;
+
+    #[test]
+    fn single_sampled_passes_draw_straight_to_the_surface() {
+        assert_eq!(color_targets("surface", None), ("surface", None));
+    }
+
+    #[test]
+    fn multisampled_passes_resolve_onto_the_surface() {
+        assert_eq!(
+            color_targets("surface", Some("multisample")),
+            ("multisample", Some("surface"))
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use wgpu::{
-    BlendState, ColorTargetState, ColorWrites, Face, FragmentState, FrontFace, MultisampleState,
-    PipelineLayoutDescriptor, PolygonMode, PrimitiveState, PrimitiveTopology, RenderPipeline,
-    RenderPipelineDescriptor, ShaderModuleDescriptor, VertexState,
+    BlendState, ColorTargetState, ColorWrites, CompareFunction, DepthBiasState, DepthStencilState,
+    Face, FragmentState, FrontFace, MultisampleState, PipelineLayoutDescriptor, PolygonMode,
+    PrimitiveState, PrimitiveTopology, RenderPipeline, RenderPipelineDescriptor, ShaderModule,
+    ShaderModuleDescriptor, StencilState, VertexState,
 };
 
-use crate::manifestation::{apex::VertexGroup, Renderer};
+use crate::manifestation::{apex::VertexGroup, nostalgia::DEPTH_FORMAT, Renderer};
 
 impl Renderer {
     pub fn pipeline(
@@ -14,11 +15,19 @@ impl Renderer {
     ) -> RenderPipeline {
         let shader = self.device.create_shader_module(shaders);
 
+        self.pipeline_from_module(vertex_group, &shader)
+    }
+
+    pub fn pipeline_from_module(
+        &mut self,
+        vertex_group: &VertexGroup,
+        shader: &ShaderModule,
+    ) -> RenderPipeline {
         let pipeline_layout = self
             .device
             .create_pipeline_layout(&PipelineLayoutDescriptor {
                 label: None,
-                bind_group_layouts: &[],
+                bind_group_layouts: &[&self.camera.layout, &self.model_layout],
                 push_constant_ranges: &[],
             });
 
@@ -28,12 +37,12 @@ impl Renderer {
                 label: None,
                 layout: Some(&pipeline_layout),
                 vertex: VertexState {
-                    module: &shader,
+                    module: shader,
                     entry_point: "vs_main",
                     buffers: &[vertex_group.layout()],
                 },
                 fragment: Some(FragmentState {
-                    module: &shader,
+                    module: shader,
                     entry_point: "fs_main",
                     targets: &[Some(ColorTargetState {
                         format: self.config.format,
@@ -50,9 +59,15 @@ impl Renderer {
                     unclipped_depth: false,
                     conservative: false,
                 },
-                depth_stencil: None,
+                depth_stencil: Some(DepthStencilState {
+                    format: DEPTH_FORMAT,
+                    depth_write_enabled: true,
+                    depth_compare: CompareFunction::LessEqual,
+                    stencil: StencilState::default(),
+                    bias: DepthBiasState::default(),
+                }),
                 multisample: MultisampleState {
-                    count: 1,
+                    count: self.sample_count,
                     mask: !0,
                     alpha_to_coverage_enabled: false,
                 },
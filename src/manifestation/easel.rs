@@ -0,0 +1,142 @@
+use wgpu::{BindGroup, RenderPass, TextureFormat};
+use winit::dpi::PhysicalSize;
+
+use crate::{
+    life::{clockwork::Time, Entity, Scene},
+    manifestation::{nostalgia::DEPTH_FORMAT, silhouette::Renderable, Renderer},
+    Engine,
+};
+
+// Everything a custom draw callback may need from the frame it is recorded into.
+pub struct RenderContext<'a> {
+    pub camera_bind_group: &'a BindGroup,
+    pub surface_format: TextureFormat,
+    pub depth_format: TextureFormat,
+    pub sample_count: u32,
+    pub size: PhysicalSize<u32>,
+    pub time: Time,
+}
+
+impl<'a> RenderContext<'a> {
+    pub fn new(renderer: &'a Renderer, scene: &Scene) -> Self {
+        Self {
+            camera_bind_group: &renderer.camera.bind_group,
+            surface_format: renderer.config.format,
+            depth_format: DEPTH_FORMAT,
+            sample_count: renderer.sample_count,
+            size: renderer.size,
+            time: scene.try_resource::<Time>().copied().unwrap_or_default(),
+        }
+    }
+
+    // Undoes whatever a callback may have changed that ignition draws rely on. Pipelines,
+    // vertex and index buffers are set by every shape, so only shared state is restored.
+    pub fn restore<'b>(&self, render_pass: &mut RenderPass<'b>)
+    where
+        'a: 'b,
+    {
+        let PhysicalSize { width, height } = self.size;
+
+        render_pass.set_bind_group(0, self.camera_bind_group, &[]);
+        render_pass.set_viewport(0.0, 0.0, width as f32, height as f32, 0.0, 1.0);
+        render_pass.set_scissor_rect(0, 0, width, height);
+        render_pass.set_blend_constant(wgpu::Color::TRANSPARENT);
+        render_pass.set_stencil_reference(0);
+    }
+}
+
+pub trait CustomDrawer {
+    fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>, context: &RenderContext<'a>);
+}
+
+struct Callback<R, F> {
+    resources: R,
+    callback: F,
+}
+
+impl<R, F> CustomDrawer for Callback<R, F>
+where
+    F: for<'a> Fn(&'a R, &mut RenderPass<'a>, &RenderContext<'a>),
+{
+    fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>, context: &RenderContext<'a>) {
+        (self.callback)(&self.resources, render_pass, context);
+    }
+}
+
+// Records arbitrary commands for one entity at its place in the draw order. The callback only
+// borrows the pass, so it cannot end it, and engine state is re-bound once it returns. Anything
+// set on the pass must outlive the frame, which is why pipelines and buffers live in `resources`.
+pub struct CustomDraw {
+    drawer: Box<dyn CustomDrawer>,
+}
+
+impl CustomDraw {
+    pub fn new<R, F>(resources: R, callback: F) -> Self
+    where
+        R: 'static,
+        F: for<'a> Fn(&'a R, &mut RenderPass<'a>, &RenderContext<'a>) + 'static,
+    {
+        Self {
+            drawer: Box::new(Callback {
+                resources,
+                callback,
+            }),
+        }
+    }
+}
+
+impl Renderable for CustomDraw {
+    fn render<'a>(&'a self, render_pass: &mut RenderPass<'a>, context: &RenderContext<'a>) {
+        self.drawer.draw(render_pass, context);
+        context.restore(render_pass);
+    }
+}
+
+impl Engine {
+    pub fn custom_draw<R, F>(&mut self, resources: R, callback: F) -> Entity
+    where
+        R: 'static,
+        F: for<'a> Fn(&'a R, &mut RenderPass<'a>, &RenderContext<'a>) + 'static,
+    {
+        let entity = self.building();
+        let custom_draw = self.renderer.track(CustomDraw::new(resources, callback));
+        self.scene.component(entity, custom_draw);
+
+        self.entity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Layer;
+
+    impl Renderable for Layer {
+        fn render<'a>(&'a self, _render_pass: &mut RenderPass<'a>, _context: &RenderContext<'a>) {}
+    }
+
+    #[test]
+    fn custom_draws_share_the_renderable_pool() {
+        let mut scene = Scene::new();
+
+        let background = scene.entity();
+        let custom = scene.entity();
+        let foreground = scene.entity();
+
+        let foreground_layer: Box<dyn Renderable> = Box::new(Layer);
+        scene.component(foreground, foreground_layer);
+        let custom_draw: Box<dyn Renderable> =
+            Box::new(CustomDraw::new((), |_, _render_pass, _context| {}));
+        scene.component(custom, custom_draw);
+        let background_layer: Box<dyn Renderable> = Box::new(Layer);
+        scene.component(background, background_layer);
+
+        scene.sort_by_entity::<Box<dyn Renderable>>();
+
+        assert_eq!(
+            scene.get::<Box<dyn Renderable>>().packed_array,
+            vec![background.raw_id(), custom.raw_id(), foreground.raw_id()]
+        );
+    }
+}
@@ -0,0 +1,436 @@
+use bytemuck::{Pod, Zeroable};
+use cgmath::{Matrix4, SquareMatrix};
+use wgpu::{
+    include_wgsl,
+    util::{BufferInitDescriptor, DeviceExt},
+    BindGroup, BindGroupDescriptor, BindGroupEntry, BindGroupLayoutDescriptor,
+    BindGroupLayoutEntry, BindingType, BlendState, Buffer, BufferBindingType, BufferUsages,
+    ColorTargetState, ColorWrites, CompareFunction, DepthBiasState, DepthStencilState,
+    FragmentState, MultisampleState, PipelineLayoutDescriptor, PrimitiveState, RenderPass,
+    RenderPipeline, RenderPipelineDescriptor, ShaderStages, StencilState, VertexState,
+};
+
+use crate::{
+    alliance::Plugin,
+    manifestation::{lens::Camera, nostalgia::DEPTH_FORMAT, Renderer},
+    Engine,
+};
+
+// Grid lines are kept at least this many pixels apart before the finest level fades out.
+pub const MIN_PIXEL_SPACING: f32 = 8.0;
+
+// Draws an infinite grid on the z = 0 plane behind every shape, in world units.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GridPlugin {
+    pub base_spacing: f32,
+    pub subdivisions: u32,
+    pub line_width: f32,
+
+    pub color_minor: [f32; 4],
+    pub color_major: [f32; 4],
+    pub color_axes: [f32; 4],
+
+    pub enabled: bool,
+}
+
+impl Default for GridPlugin {
+    fn default() -> Self {
+        Self {
+            base_spacing: 1.0,
+            subdivisions: 10,
+            line_width: 1.0,
+
+            color_minor: [0.3, 0.3, 0.3, 0.5],
+            color_major: [0.5, 0.5, 0.5, 0.8],
+            color_axes: [0.9, 0.9, 0.9, 1.0],
+
+            enabled: true,
+        }
+    }
+}
+
+impl GridPlugin {
+    pub fn is_drawn(&self) -> bool {
+        self.enabled && self.base_spacing > 0.0 && self.subdivisions >= 2
+    }
+
+    pub fn levels(&self, world_per_pixel: f32) -> GridLevels {
+        let subdivisions = self.subdivisions as f32;
+        let lod = (world_per_pixel * MIN_PIXEL_SPACING / self.base_spacing).log(subdivisions);
+        let spacing = self.base_spacing * subdivisions.powf(lod.floor());
+
+        GridLevels {
+            spacing: [
+                spacing,
+                spacing * subdivisions,
+                spacing * subdivisions * subdivisions,
+            ],
+            fade: lod - lod.floor(),
+        }
+    }
+
+    pub fn line_coverage(&self, coordinate: f32, spacing: f32, world_per_pixel: f32) -> f32 {
+        let cell = coordinate / spacing;
+        let distance = (cell - cell.round()).abs() * spacing / world_per_pixel;
+
+        (self.line_width * 0.5 + 0.5 - distance).clamp(0.0, 1.0)
+    }
+
+    pub fn level_color(&self, level: f32) -> [f32; 4] {
+        if level <= 0.0 {
+            let [r, g, b, a] = self.color_minor;
+            return [r, g, b, a * (1.0 + level)];
+        }
+
+        mix(self.color_minor, self.color_major, level.min(1.0))
+    }
+
+    // CPU reference of the fragment shader in shaders/grid.wgsl.
+    pub fn color_at(&self, position: [f32; 2], world_per_pixel: f32) -> [f32; 4] {
+        let levels = self.levels(world_per_pixel);
+        let coverage = |coordinate: f32, spacing: f32| {
+            self.line_coverage(coordinate, spacing, world_per_pixel)
+        };
+
+        let mut color = [0.0; 4];
+        for (level, spacing) in levels.spacing.into_iter().enumerate() {
+            let level_coverage = coverage(position[0], spacing).max(coverage(position[1], spacing));
+            color = mix(
+                color,
+                self.level_color(level as f32 - levels.fade),
+                level_coverage,
+            );
+        }
+
+        let axis = |coordinate: f32| {
+            (self.line_width * 0.5 + 0.5 - coordinate.abs() / world_per_pixel).clamp(0.0, 1.0)
+        };
+        mix(
+            color,
+            self.color_axes,
+            axis(position[0]).max(axis(position[1])),
+        )
+    }
+}
+
+impl Plugin for GridPlugin {
+    fn build(&self, engine: &mut Engine) {
+        let grid = GridPass::new(&engine.renderer, *self);
+        if let Some(old) = engine.renderer.grid.replace(grid) {
+            engine.renderer.release(old);
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GridLevels {
+    pub spacing: [f32; 3],
+    pub fade: f32,
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GridUniform {
+    pub inverse_view_projection: [[f32; 4]; 4],
+    pub color_minor: [f32; 4],
+    pub color_major: [f32; 4],
+    pub color_axes: [f32; 4],
+    pub base_spacing: f32,
+    pub subdivisions: f32,
+    pub line_width: f32,
+    pub min_pixel_spacing: f32,
+}
+
+unsafe impl Zeroable for GridUniform {}
+unsafe impl Pod for GridUniform {}
+
+impl GridUniform {
+    pub fn new(grid: &GridPlugin, view_projection: Matrix4<f32>) -> Self {
+        let inverse_view_projection = view_projection.invert().unwrap_or_else(Matrix4::identity);
+
+        Self {
+            inverse_view_projection: inverse_view_projection.into(),
+            color_minor: grid.color_minor,
+            color_major: grid.color_major,
+            color_axes: grid.color_axes,
+            base_spacing: grid.base_spacing,
+            subdivisions: grid.subdivisions as f32,
+            line_width: grid.line_width,
+            min_pixel_spacing: MIN_PIXEL_SPACING,
+        }
+    }
+}
+
+pub struct GridPass {
+    pub settings: GridPlugin,
+
+    pub pipeline: RenderPipeline,
+    pub buffer: Buffer,
+    pub bind_group: BindGroup,
+}
+
+impl GridPass {
+    pub fn new(renderer: &Renderer, settings: GridPlugin) -> Self {
+        let device = &renderer.device;
+
+        let buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("Grid Buffer"),
+            contents: bytemuck::cast_slice(&[GridUniform::new(&settings, Matrix4::identity())]),
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        });
+
+        let layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("Grid Bind Group Layout"),
+            entries: &[BindGroupLayoutEntry {
+                binding: 0,
+                visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let bind_group = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("Grid Bind Group"),
+            layout: &layout,
+            entries: &[BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+        });
+
+        let shader = device.create_shader_module(include_wgsl!("shaders/grid.wgsl"));
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("Grid Pipeline Layout"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            label: Some("Grid Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(ColorTargetState {
+                    format: renderer.config.format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: Some(DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled: false,
+                depth_compare: CompareFunction::Always,
+                stencil: StencilState::default(),
+                bias: DepthBiasState::default(),
+            }),
+            multisample: MultisampleState {
+                count: renderer.sample_count,
+                ..Default::default()
+            },
+            multiview: None,
+        });
+
+        Self {
+            settings,
+
+            pipeline,
+            buffer,
+            bind_group,
+        }
+    }
+
+    pub fn render<'a>(&'a self, render_pass: &mut RenderPass<'a>) {
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
+
+impl Engine {
+    pub fn set_grid_enabled(&mut self, enabled: bool) {
+        if let Some(grid) = self.renderer.grid.as_mut() {
+            grid.settings.enabled = enabled;
+        }
+    }
+
+    pub fn update_grid(&mut self) {
+        let aspect = self.renderer.aspect_ratio();
+        let view_projection = self
+            .scene
+            .try_resource::<Camera>()
+            .map_or_else(Matrix4::identity, |camera| camera.view_projection(aspect));
+
+        if let Some(grid) = self
+            .renderer
+            .grid
+            .as_ref()
+            .filter(|grid| grid.settings.is_drawn())
+        {
+            let uniform = GridUniform::new(&grid.settings, view_projection);
+
+            self.renderer
+                .queue
+                .write_buffer(&grid.buffer, 0, bytemuck::cast_slice(&[uniform]));
+        }
+    }
+}
+
+fn mix(below: [f32; 4], above: [f32; 4], amount: f32) -> [f32; 4] {
+    let mut mixed = below;
+    for (channel, above) in mixed.iter_mut().zip(above) {
+        *channel += (above - *channel) * amount;
+    }
+
+    mixed
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::manifestation::lattice::{GridPlugin, MIN_PIXEL_SPACING};
+
+    fn grid() -> GridPlugin {
+        GridPlugin {
+            color_minor: [0.0, 0.0, 1.0, 1.0],
+            color_major: [0.0, 1.0, 0.0, 1.0],
+            color_axes: [1.0, 0.0, 0.0, 1.0],
+            ..GridPlugin::default()
+        }
+    }
+
+    fn close(left: f32, right: f32) -> bool {
+        (left - right).abs() < 1e-4
+    }
+
+    #[test]
+    fn the_finest_level_is_exactly_the_minimum_pixel_spacing_apart() {
+        let world_per_pixel = 1.0 / MIN_PIXEL_SPACING;
+        let levels = grid().levels(world_per_pixel);
+
+        assert!(close(levels.spacing[0], 1.0));
+        assert!(close(levels.spacing[1], 10.0));
+        assert!(close(levels.fade, 0.0));
+    }
+
+    #[test]
+    fn zooming_out_fades_the_finest_level() {
+        let world_per_pixel = 10.0_f32.sqrt() / MIN_PIXEL_SPACING;
+        let levels = grid().levels(world_per_pixel);
+
+        assert!(close(levels.spacing[0], 1.0));
+        assert!(close(levels.fade, 0.5));
+    }
+
+    #[test]
+    fn zooming_out_by_a_subdivision_moves_up_a_level() {
+        let levels = grid().levels(10.0 / MIN_PIXEL_SPACING);
+
+        assert!(close(levels.spacing[0], 10.0));
+        assert!(close(levels.spacing[2], 1000.0));
+    }
+
+    #[test]
+    fn zooming_in_past_the_base_spacing_keeps_subdividing() {
+        let levels = grid().levels(0.01 / MIN_PIXEL_SPACING);
+
+        assert!(close(levels.spacing[0], 0.01));
+    }
+
+    #[test]
+    fn lines_are_a_constant_number_of_pixels_wide() {
+        let grid = GridPlugin {
+            line_width: 2.0,
+            ..grid()
+        };
+
+        for world_per_pixel in [0.001, 0.1, 10.0] {
+            let spacing = 100.0 * world_per_pixel;
+
+            assert_eq!(
+                grid.line_coverage(0.5 * world_per_pixel, spacing, world_per_pixel),
+                1.0
+            );
+            assert!(close(
+                grid.line_coverage(1.5 * world_per_pixel, spacing, world_per_pixel),
+                0.0
+            ));
+            assert_eq!(
+                grid.line_coverage(3.0 * world_per_pixel, spacing, world_per_pixel),
+                0.0
+            );
+        }
+    }
+
+    #[test]
+    fn axes_are_drawn_over_grid_lines() {
+        assert_eq!(grid().color_at([0.0, 3.0], 0.01), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(grid().color_at([3.0, 0.0], 10.0), [1.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn space_between_lines_is_transparent() {
+        assert_eq!(grid().color_at([3.5, 7.5], 1.0 / MIN_PIXEL_SPACING)[3], 0.0);
+    }
+
+    #[test]
+    fn major_lines_use_the_major_color_at_a_level_boundary() {
+        let world_per_pixel = 1.0 / MIN_PIXEL_SPACING;
+
+        assert_eq!(
+            grid().color_at([10.0, 3.5], world_per_pixel),
+            [0.0, 1.0, 0.0, 1.0]
+        );
+        assert_eq!(
+            grid().color_at([3.0, 3.5], world_per_pixel),
+            [0.0, 0.0, 1.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn crossing_a_level_boundary_does_not_pop() {
+        let grid = grid();
+        let boundary = 10.0 / MIN_PIXEL_SPACING;
+
+        // Compared premultiplied, as that is what alpha blending shows on screen.
+        let premultiplied = |[r, g, b, a]: [f32; 4]| [r * a, g * a, b * a, a];
+
+        for position in [[10.0, 3.5], [100.0, 3.5], [20.0, 3.5], [3.0, 3.5]] {
+            let below = premultiplied(grid.color_at(position, boundary * 0.9999));
+            let above = premultiplied(grid.color_at(position, boundary * 1.0001));
+
+            for (below, above) in below.into_iter().zip(above) {
+                assert!(
+                    (below - above).abs() < 0.01,
+                    "{:?} pops at {:?}",
+                    position,
+                    boundary
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn the_pass_is_skipped_when_the_grid_is_disabled() {
+        assert!(grid().is_drawn());
+        assert!(!GridPlugin {
+            enabled: false,
+            ..grid()
+        }
+        .is_drawn());
+        assert!(!GridPlugin {
+            subdivisions: 1,
+            ..grid()
+        }
+        .is_drawn());
+    }
+}
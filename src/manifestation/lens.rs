@@ -0,0 +1,220 @@
+use bytemuck::{Pod, Zeroable};
+use cgmath::{perspective, Deg, Matrix4, Point3, Rad, SquareMatrix, Vector3};
+use wgpu::{
+    util::{BufferInitDescriptor, DeviceExt},
+    BindGroup, BindGroupDescriptor, BindGroupEntry, BindGroupLayout, BindGroupLayoutDescriptor,
+    BindGroupLayoutEntry, BindingType, Buffer, BufferBindingType, BufferUsages, Device,
+    ShaderStages,
+};
+
+use crate::{manifestation::Renderer, Engine};
+
+#[rustfmt::skip]
+pub const OPENGL_TO_WGPU_MATRIX: Matrix4<f32> = Matrix4::new(
+    1.0, 0.0, 0.0, 0.0,
+    0.0, 1.0, 0.0, 0.0,
+    0.0, 0.0, 0.5, 0.0,
+    0.0, 0.0, 0.5, 1.0,
+);
+
+// Right-handed world space with +Y up. A camera with zero yaw and pitch looks down -Z,
+// positive yaw turns towards +X and positive pitch looks up. Angles are in radians.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Camera {
+    pub position: [f32; 3],
+    pub yaw: f32,
+    pub pitch: f32,
+
+    pub fov_y: f32,
+    pub near: f32,
+    pub far: f32,
+}
+
+impl Camera {
+    pub fn new(position: [f32; 3], yaw: f32, pitch: f32) -> Self {
+        Self {
+            position,
+            yaw,
+            pitch,
+
+            fov_y: 45.0,
+            near: 0.1,
+            far: 100.0,
+        }
+    }
+
+    pub fn forward(&self) -> Vector3<f32> {
+        Vector3::new(
+            self.pitch.cos() * self.yaw.sin(),
+            self.pitch.sin(),
+            -self.pitch.cos() * self.yaw.cos(),
+        )
+    }
+
+    pub fn view_projection(&self, aspect: f32) -> Matrix4<f32> {
+        let view = Matrix4::look_to_rh(
+            Point3::from(self.position),
+            self.forward(),
+            Vector3::unit_y(),
+        );
+        let projection = perspective(Deg(self.fov_y), aspect, self.near, self.far);
+
+        OPENGL_TO_WGPU_MATRIX * projection * view
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CameraUniform {
+    pub view_projection: [[f32; 4]; 4],
+}
+
+unsafe impl Zeroable for CameraUniform {}
+unsafe impl Pod for CameraUniform {}
+
+impl CameraUniform {
+    pub fn new(camera: Option<&Camera>, aspect: f32) -> Self {
+        let view_projection = match camera {
+            Some(camera) => camera.view_projection(aspect),
+            None => Matrix4::identity(),
+        };
+
+        Self {
+            view_projection: view_projection.into(),
+        }
+    }
+}
+
+pub struct CameraBinding {
+    pub buffer: Buffer,
+    pub layout: BindGroupLayout,
+    pub bind_group: BindGroup,
+}
+
+impl CameraBinding {
+    pub fn new(device: &Device) -> Self {
+        let buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("Camera Buffer"),
+            contents: bytemuck::cast_slice(&[CameraUniform::new(None, 1.0)]),
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        });
+
+        let layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("Camera Bind Group Layout"),
+            entries: &[BindGroupLayoutEntry {
+                binding: 0,
+                visibility: ShaderStages::VERTEX,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let bind_group = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("Camera Bind Group"),
+            layout: &layout,
+            entries: &[BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+        });
+
+        Self {
+            buffer,
+            layout,
+            bind_group,
+        }
+    }
+}
+
+impl Engine {
+    pub fn set_camera(&mut self, camera: Camera) {
+        self.scene.insert_resource(camera);
+    }
+
+    pub fn update_camera(&mut self) {
+        let aspect = self.renderer.aspect_ratio();
+        let uniform = CameraUniform::new(self.scene.try_resource::<Camera>(), aspect);
+
+        self.renderer.queue.write_buffer(
+            &self.renderer.camera.buffer,
+            0,
+            bytemuck::cast_slice(&[uniform]),
+        );
+    }
+}
+
+impl Renderer {
+    pub fn aspect_ratio(&self) -> f32 {
+        self.size.width as f32 / self.size.height.max(1) as f32
+    }
+}
+
+pub fn radians(degrees: f32) -> f32 {
+    Rad::from(Deg(degrees)).0
+}
+
+#[cfg(test)]
+mod tests {
+    use cgmath::{Matrix4, SquareMatrix, Vector4};
+
+    use crate::manifestation::lens::{radians, Camera, CameraUniform};
+
+    fn project(camera: &Camera, point: [f32; 3]) -> [f32; 3] {
+        let clip = camera.view_projection(1.0) * Vector4::new(point[0], point[1], point[2], 1.0);
+
+        [clip.x / clip.w, clip.y / clip.w, clip.z / clip.w]
+    }
+
+    #[test]
+    fn without_a_camera_the_uniform_is_the_identity() {
+        let uniform = CameraUniform::new(None, 1.5);
+
+        let identity: [[f32; 4]; 4] = Matrix4::identity().into();
+
+        assert_eq!(uniform.view_projection, identity);
+    }
+
+    #[test]
+    fn a_default_camera_looks_down_negative_z() {
+        let camera = Camera::new([0.0, 0.0, 0.0], 0.0, 0.0);
+        let [x, y, z] = project(&camera, [0.0, 0.0, -5.0]);
+
+        assert!(x.abs() < 1e-5 && y.abs() < 1e-5);
+        assert!((0.0..=1.0).contains(&z));
+    }
+
+    #[test]
+    fn positive_yaw_turns_towards_positive_x() {
+        let camera = Camera::new([0.0, 0.0, 0.0], radians(90.0), 0.0);
+        let [x, y, _z] = project(&camera, [5.0, 0.0, 0.0]);
+
+        assert!(x.abs() < 1e-5 && y.abs() < 1e-5);
+    }
+
+    #[test]
+    fn positive_pitch_looks_up() {
+        let camera = Camera::new([0.0, 0.0, 0.0], 0.0, radians(30.0));
+        let [_x, y, _z] = project(&camera, [0.0, 0.0, -5.0]);
+
+        assert!(y < 0.0);
+    }
+
+    #[test]
+    fn moving_the_camera_shifts_the_scene() {
+        let camera = Camera::new([1.0, 0.0, 5.0], 0.0, 0.0);
+        let [x, y, _z] = project(&camera, [1.0, 0.0, 0.0]);
+
+        assert!(x.abs() < 1e-5 && y.abs() < 1e-5);
+    }
+
+    #[test]
+    fn depth_grows_with_distance() {
+        let camera = Camera::new([0.0, 0.0, 0.0], 0.0, 0.0);
+
+        assert!(project(&camera, [0.0, 0.0, -2.0])[2] < project(&camera, [0.0, 0.0, -20.0])[2]);
+    }
+}
@@ -1,11 +1,18 @@
 use serde::Serialize;
 use wgpu::{ShaderModuleDescriptor, VertexFormat};
 
-use crate::{manifestation::apex::VertexGroup, Engine};
+use crate::{
+    life::Entity,
+    manifestation::{
+        apex::{IndexData, VertexGroup},
+        metamorphosis::ShaderSource,
+    },
+    Engine,
+};
 
 impl Engine {
     pub fn component<G: 'static>(&mut self, component: G) -> &mut Self {
-        let entity = self.scene.get_current_entity();
+        let entity = self.building();
         self.scene.component(entity, component);
 
         self
@@ -17,15 +24,22 @@ impl Engine {
         step: usize,
         format: VertexFormat,
     ) -> &mut Self {
-        let entity = self.scene.get_current_entity();
+        self.data_slice(&data, step, format)
+    }
+
+    pub fn data_slice<G: Serialize + std::fmt::Debug>(
+        &mut self,
+        data: &[G],
+        step: usize,
+        format: VertexFormat,
+    ) -> &mut Self {
+        let entity = self.building();
 
-        if self.scene.component_exists::<VertexGroup>(entity) {
-            self.scene
-                .get_component_mut::<VertexGroup>(entity)
-                .data(data, step, format);
+        if let Some(vertex_group) = self.scene.get_component_mut::<VertexGroup>(entity) {
+            vertex_group.data_slice(data, step, format);
         } else {
             let mut vertex_group = VertexGroup::new();
-            vertex_group.data(data, step, format);
+            vertex_group.data_slice(data, step, format);
 
             self.scene.component(entity, vertex_group);
         }
@@ -45,18 +59,69 @@ impl Engine {
         self.data(data, 3, VertexFormat::Float32x3)
     }
 
-    pub fn entity(&mut self) -> usize {
-        self.scene.entity()
+    pub fn uniform_rgb(&mut self, color: [f32; 3]) -> &mut Self {
+        let entity = self.building();
+        let num_vertices = self
+            .scene
+            .get_component::<VertexGroup>(entity)
+            .map_or(0, |vertex_group| vertex_group.num_vertices);
+
+        self.data_slice(
+            &color.repeat(num_vertices as usize),
+            3,
+            VertexFormat::Float32x3,
+        )
     }
 
-    pub fn doritos(&mut self) -> usize {
-        let entity = self.scene.get_current_entity();
-        let shaders = self.scene.take_component::<ShaderModuleDescriptor>(entity);
-        let vertex_group = self.scene.get_component::<VertexGroup>(entity);
+    pub fn indices<I: Into<IndexData>>(&mut self, indices: I) -> &mut Self {
+        self.component::<IndexData>(indices.into())
+    }
 
-        let doritos = self.renderer.doritos(vertex_group, shaders);
+    // The entity the builder methods add to. The first of them spawns it, and `entity`, `doritos`
+    // and the other shape builders finish it and hand it back.
+    pub fn building(&mut self) -> usize {
+        match self.building {
+            Some(entity) if self.scene.is_alive(entity) => entity.raw_id(),
+            _ => {
+                let entity = self.scene.entity();
+                self.building = Some(entity);
+
+                entity.raw_id()
+            }
+        }
+    }
+
+    pub fn entity(&mut self) -> Entity {
+        let entity = self.building();
+        self.building = None;
+
+        self.scene.handle(entity)
+    }
+
+    pub fn doritos(&mut self) -> Entity {
+        let entity = self.building();
+        let shaders = self.take_shaders(entity);
+        let indices = self.scene.remove_component::<IndexData>(entity);
+        let vertex_group = self.scene.get_component::<VertexGroup>(entity).unwrap();
+
+        let doritos = match indices {
+            Some(indices) => self
+                .renderer
+                .doritos_indexed(vertex_group, &indices, shaders),
+            None => self.renderer.doritos(vertex_group, shaders),
+        };
         self.scene.component(entity, doritos);
 
-        return self.scene.entity();
+        self.entity()
+    }
+
+    pub fn take_shaders(&mut self, entity: usize) -> ShaderSource {
+        match self.scene.remove_component::<ShaderSource>(entity) {
+            Some(shaders) => shaders,
+            None => self
+                .scene
+                .take_component::<ShaderModuleDescriptor>(entity)
+                .into(),
+        }
     }
 }
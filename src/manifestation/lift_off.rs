@@ -4,7 +4,7 @@ use wgpu::{
 };
 
 use winit::{
-    dpi::PhysicalSize,
+    dpi::{LogicalSize, PhysicalSize},
     event_loop::EventLoop,
     platform::unix::EventLoopExtUnix,
     window::{Window, WindowBuilder},
@@ -16,6 +16,7 @@ pub fn create_window(parameters: &Parameters) -> (EventLoop<()>, Window, Physica
     let event_loop = EventLoop::new_any_thread();
     let window = WindowBuilder::new()
         .with_title(parameters.window_title.clone())
+        .with_inner_size(LogicalSize::new(parameters.width, parameters.height))
         .build(&event_loop)
         .unwrap();
 
@@ -32,13 +33,23 @@ pub fn generate_default_configuration(
     size: &PhysicalSize<u32>,
     surface: &Surface,
     adapter: &Adapter,
+    vsync: bool,
 ) -> SurfaceConfiguration {
     SurfaceConfiguration {
         usage: TextureUsages::RENDER_ATTACHMENT,
-        format: surface.get_supported_formats(&adapter)[0],
+        format: surface.get_supported_formats(adapter)[0],
         width: size.width,
         height: size.height,
-        present_mode: PresentMode::Fifo,
+        present_mode: present_mode(vsync),
+    }
+}
+
+// Surfaces that cannot present immediately fall back to Fifo when configured.
+pub fn present_mode(vsync: bool) -> PresentMode {
+    if vsync {
+        PresentMode::Fifo
+    } else {
+        PresentMode::Immediate
     }
 }
 
@@ -46,7 +57,7 @@ pub async fn get_adapter(instance: &Instance, surface: &Surface) -> Adapter {
     instance
         .request_adapter(&RequestAdapterOptions {
             power_preference: PowerPreference::default(),
-            compatible_surface: Some(&surface),
+            compatible_surface: Some(surface),
             force_fallback_adapter: false,
         })
         .await
@@ -57,8 +68,11 @@ pub async fn get_device(adapter: &Adapter) -> (Device, Queue) {
     adapter
         .request_device(
             &DeviceDescriptor {
-                features: Features::empty(),
-                limits: Limits::default(),
+                features: adapter.features() & Features::PUSH_CONSTANTS,
+                limits: Limits {
+                    max_push_constant_size: adapter.limits().max_push_constant_size.min(128),
+                    ..Limits::default()
+                },
                 label: None,
             },
             None,
@@ -66,3 +80,16 @@ pub async fn get_device(adapter: &Adapter) -> (Device, Queue) {
         .await
         .expect("Error: Failed to create device - Ignition")
 }
+
+#[cfg(test)]
+mod tests {
+    use wgpu::PresentMode;
+
+    use crate::manifestation::lift_off::present_mode;
+
+    #[test]
+    fn vsync_picks_the_present_mode() {
+        assert_eq!(present_mode(true), PresentMode::Fifo);
+        assert_eq!(present_mode(false), PresentMode::Immediate);
+    }
+}
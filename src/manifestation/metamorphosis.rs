@@ -0,0 +1,272 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use wgpu::{RenderPipeline, ShaderModuleDescriptor};
+
+use crate::{
+    calamity::IgnitionError,
+    manifestation::{apex::VertexGroup, silhouette::Renderable, Renderer},
+    Engine,
+};
+
+// `File` shaders are read at runtime, and with the `hot_reload` feature their pipelines are rebuilt when the file changes.
+pub enum ShaderSource {
+    Embedded(ShaderModuleDescriptor<'static>),
+    File(PathBuf),
+}
+
+impl From<ShaderModuleDescriptor<'static>> for ShaderSource {
+    fn from(descriptor: ShaderModuleDescriptor<'static>) -> Self {
+        ShaderSource::Embedded(descriptor)
+    }
+}
+
+impl From<PathBuf> for ShaderSource {
+    fn from(path: PathBuf) -> Self {
+        ShaderSource::File(path)
+    }
+}
+
+#[derive(Debug)]
+pub struct WatchedShader {
+    pub path: PathBuf,
+    vertex_layout: VertexGroup,
+    changed: Arc<AtomicBool>,
+
+    #[cfg(feature = "hot_reload")]
+    _watcher: notify::RecommendedWatcher,
+}
+
+impl WatchedShader {
+    // The parent directory is watched, so editors that save by replacing the file are still picked up.
+    #[cfg(feature = "hot_reload")]
+    pub fn new(path: &Path, vertex_group: &VertexGroup) -> Result<Self, IgnitionError> {
+        use notify::{RecursiveMode, Watcher};
+
+        let path = path.canonicalize().map_err(|error| io_error(path, error))?;
+        let changed = Arc::new(AtomicBool::new(false));
+
+        let flag = changed.clone();
+        let watched = path.clone();
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                if event.is_ok_and(|event| touches(&event, &watched)) {
+                    flag.store(true, Ordering::Release);
+                }
+            })
+            .map_err(|error| io_error(&path, error))?;
+        watcher
+            .watch(path.parent().unwrap_or(&path), RecursiveMode::NonRecursive)
+            .map_err(|error| io_error(&path, error))?;
+
+        Ok(Self {
+            path,
+            vertex_layout: vertex_group.layout_only(),
+            changed,
+
+            _watcher: watcher,
+        })
+    }
+
+    pub fn mark_changed(&self) {
+        self.changed.store(true, Ordering::Release);
+    }
+
+    pub fn take_changed(&self) -> bool {
+        self.changed.swap(false, Ordering::AcqRel)
+    }
+
+    // A shader that fails to load or validate is logged and the previous pipeline stays in use.
+    pub fn reload(&self, renderer: &mut Renderer) -> Option<RenderPipeline> {
+        if !self.take_changed() {
+            return None;
+        }
+
+        match renderer.file_pipeline(&self.path, &self.vertex_layout) {
+            Ok(pipeline) => {
+                log::info!("Reloaded shader {}", self.path.display());
+                Some(pipeline)
+            }
+            Err(error) => {
+                log::error!("{}", error);
+                None
+            }
+        }
+    }
+}
+
+impl Renderer {
+    // Embedded and file shaders that fail to load panic, like an invalid `include_wgsl!` would.
+    pub fn shader_pipeline(
+        &mut self,
+        vertex_group: &VertexGroup,
+        shaders: ShaderSource,
+    ) -> (RenderPipeline, Option<WatchedShader>) {
+        match shaders {
+            ShaderSource::Embedded(descriptor) => (self.pipeline(vertex_group, descriptor), None),
+            ShaderSource::File(path) => {
+                let pipeline = self
+                    .file_pipeline(&path, vertex_group)
+                    .unwrap_or_else(|error| panic!("{}", error));
+
+                (pipeline, watch(&path, vertex_group))
+            }
+        }
+    }
+
+    pub fn file_pipeline(
+        &mut self,
+        path: &Path,
+        vertex_group: &VertexGroup,
+    ) -> Result<RenderPipeline, IgnitionError> {
+        let source = read_shader(path)?;
+        let label = path.display().to_string();
+
+        self.pipeline_for(&label, vertex_group, &label, &source)
+    }
+}
+
+impl Engine {
+    pub fn shader_file<P: Into<PathBuf>>(&mut self, path: P) -> &mut Self {
+        self.component(ShaderSource::File(path.into()))
+    }
+
+    pub fn reload_shaders(&mut self) {
+        for scene in std::iter::once(&mut self.scene).chain(self.scenes.iter_mut()) {
+            if let Some(shapes) = scene.try_get_mut::<Box<dyn Renderable>>() {
+                for (_entity, shape) in shapes.iter_with_entities_unmarked_mut() {
+                    shape.reload_shader(&mut self.renderer);
+                }
+            }
+        }
+    }
+}
+
+pub fn read_shader(path: &Path) -> Result<String, IgnitionError> {
+    fs::read_to_string(path).map_err(|error| io_error(path, error))
+}
+
+#[cfg(feature = "hot_reload")]
+fn watch(path: &Path, vertex_group: &VertexGroup) -> Option<WatchedShader> {
+    WatchedShader::new(path, vertex_group)
+        .map_err(|error| log::warn!("{}, it will not be reloaded", error))
+        .ok()
+}
+
+#[cfg(not(feature = "hot_reload"))]
+fn watch(_path: &Path, _vertex_group: &VertexGroup) -> Option<WatchedShader> {
+    None
+}
+
+#[cfg(feature = "hot_reload")]
+pub fn touches(event: &notify::Event, path: &Path) -> bool {
+    (event.kind.is_modify() || event.kind.is_create())
+        && event.paths.iter().any(|changed| changed == path)
+}
+
+fn io_error<E: std::fmt::Display>(path: &Path, error: E) -> IgnitionError {
+    IgnitionError::Io {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use wgpu::{include_wgsl, VertexFormat};
+
+    use crate::{
+        calamity::IgnitionError,
+        manifestation::{
+            apex::VertexGroup,
+            metamorphosis::{read_shader, ShaderSource},
+        },
+    };
+
+    #[test]
+    fn embedded_and_file_shaders_convert_into_sources() {
+        let embedded: ShaderSource = include_wgsl!("shaders/grid.wgsl").into();
+        let file: ShaderSource = PathBuf::from("shaders/shape.wgsl").into();
+
+        assert!(matches!(embedded, ShaderSource::Embedded(_)));
+        assert!(
+            matches!(file, ShaderSource::File(path) if path == Path::new("shaders/shape.wgsl"))
+        );
+    }
+
+    #[test]
+    fn shader_files_are_read_from_disk() {
+        let source = read_shader(Path::new("src/manifestation/shaders/grid.wgsl")).unwrap();
+
+        assert_eq!(source, include_str!("shaders/grid.wgsl"));
+    }
+
+    #[test]
+    fn missing_shader_files_report_their_path() {
+        let path = Path::new("shaders/missing.wgsl");
+
+        assert!(matches!(
+            read_shader(path),
+            Err(IgnitionError::Io { path: reported, .. }) if reported == path
+        ));
+    }
+
+    #[test]
+    fn watched_layouts_drop_the_vertex_data() {
+        let mut vertex_group = VertexGroup::new();
+        vertex_group.data([0.0_f32, 1.0, 1.0, 0.0], 2, VertexFormat::Float32x2);
+
+        let layout = vertex_group.layout_only();
+
+        assert_eq!(layout.data.len(), 0);
+        assert_eq!(layout.layout(), vertex_group.layout());
+    }
+
+    #[cfg(feature = "hot_reload")]
+    #[test]
+    fn only_changes_to_the_watched_file_count() {
+        use notify::{
+            event::{AccessKind, ModifyKind},
+            Event, EventKind,
+        };
+
+        let path = Path::new("/shaders/shape.wgsl");
+        let modified = |changed: &str| {
+            Event::new(EventKind::Modify(ModifyKind::Any)).add_path(PathBuf::from(changed))
+        };
+
+        assert!(super::touches(&modified("/shaders/shape.wgsl"), path));
+        assert!(!super::touches(&modified("/shaders/grid.wgsl"), path));
+        assert!(!super::touches(
+            &Event::new(EventKind::Access(AccessKind::Any)).add_path(path.to_path_buf()),
+            path
+        ));
+    }
+
+    #[cfg(feature = "hot_reload")]
+    #[test]
+    fn saving_a_watched_shader_marks_it_changed() {
+        use std::{fs, time::Duration};
+
+        use crate::{dusk::wait_until, manifestation::metamorphosis::WatchedShader};
+
+        let directory =
+            std::env::temp_dir().join(format!("ignition-shaders-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("shape.wgsl");
+        fs::write(&path, "// first").unwrap();
+
+        let shader = WatchedShader::new(&path, &VertexGroup::new()).unwrap();
+        assert!(!shader.take_changed());
+
+        fs::write(&path, "// second").unwrap();
+        let changed = wait_until(|| shader.take_changed(), Duration::from_secs(5));
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert!(changed);
+    }
+}
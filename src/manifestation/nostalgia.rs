@@ -1,28 +1,98 @@
 use wgpu::{
     util::{BufferInitDescriptor, DeviceExt},
-    Buffer, BufferUsages,
+    BufferAddress, BufferUsages, Device, Extent3d, SurfaceConfiguration, Texture,
+    TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
 };
 
-use crate::manifestation::{apex::VertexGroup, Renderer};
+use crate::manifestation::{
+    apex::{IndexData, VertexGroup},
+    pantry::{padded, GpuBuffer},
+    Renderer,
+};
+
+pub const DEPTH_FORMAT: TextureFormat = TextureFormat::Depth32Float;
 
 impl Renderer {
-    pub fn vertex_buffer(&mut self, vertex_group: &VertexGroup) -> Buffer {
-        let vertex_buffer = self.device.create_buffer_init(&BufferInitDescriptor {
-            label: None,
-            contents: &vertex_group.get(),
-            usage: BufferUsages::VERTEX,
-        });
-
-        vertex_buffer
+    pub fn vertex_buffer(&mut self, vertex_group: &VertexGroup) -> GpuBuffer {
+        let contents = vertex_group.get();
+
+        match self.vertex_arena.allocate(&self.queue, &contents) {
+            Some(allocation) => GpuBuffer::Shared(allocation),
+            None => dedicated_buffer(&self.device, &contents, BufferUsages::VERTEX),
+        }
+    }
+
+    pub fn index_buffer(&mut self, indices: &IndexData) -> GpuBuffer {
+        let contents = indices.get();
+
+        match self.index_arena.allocate(&self.queue, contents) {
+            Some(allocation) => GpuBuffer::Shared(allocation),
+            None => dedicated_buffer(&self.device, contents, BufferUsages::INDEX),
+        }
+    }
+
+    pub fn depth_texture(&mut self) -> Texture {
+        create_depth_texture(&self.device, &self.config, self.sample_count)
     }
 
-    pub fn index_buffer(&mut self, indices: &Vec<u16>) -> Buffer {
-        let index_buffer = self.device.create_buffer_init(&BufferInitDescriptor {
-            label: None,
-            contents: bytemuck::cast_slice(indices),
-            usage: BufferUsages::INDEX,
-        });
+    pub fn multisample_texture(&mut self) -> Option<Texture> {
+        create_multisample_texture(&self.device, &self.config, self.sample_count)
+    }
+}
+
+// Padded and writable, so the contents can be replaced in place later.
+fn dedicated_buffer(device: &Device, contents: &[u8], usage: BufferUsages) -> GpuBuffer {
+    let contents = padded(contents);
+    let buffer = device.create_buffer_init(&BufferInitDescriptor {
+        label: None,
+        contents: &contents,
+        usage: usage | BufferUsages::COPY_DST,
+    });
+
+    GpuBuffer::Dedicated(buffer, contents.len() as BufferAddress)
+}
+
+pub fn create_depth_texture(
+    device: &Device,
+    config: &SurfaceConfiguration,
+    sample_count: u32,
+) -> Texture {
+    device.create_texture(&TextureDescriptor {
+        label: Some("Depth Texture"),
+        size: Extent3d {
+            width: config.width,
+            height: config.height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count,
+        dimension: TextureDimension::D2,
+        format: DEPTH_FORMAT,
+        usage: TextureUsages::RENDER_ATTACHMENT,
+    })
+}
 
-        index_buffer
+// Drawn into instead of the surface when multisampling, then resolved onto it at the end of the pass.
+pub fn create_multisample_texture(
+    device: &Device,
+    config: &SurfaceConfiguration,
+    sample_count: u32,
+) -> Option<Texture> {
+    if sample_count == 1 {
+        return None;
     }
+
+    Some(device.create_texture(&TextureDescriptor {
+        label: Some("Multisample Texture"),
+        size: Extent3d {
+            width: config.width,
+            height: config.height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count,
+        dimension: TextureDimension::D2,
+        format: config.format,
+        usage: TextureUsages::RENDER_ATTACHMENT,
+    }))
 }
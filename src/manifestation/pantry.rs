@@ -0,0 +1,342 @@
+use std::{cell::RefCell, ops::Range, rc::Rc};
+
+use wgpu::{
+    Buffer, BufferAddress, BufferDescriptor, BufferSlice, BufferUsages, Device, Queue,
+    COPY_BUFFER_ALIGNMENT,
+};
+
+use crate::manifestation::Renderer;
+
+pub const ARENA_SIZE: BufferAddress = 4 * 1024 * 1024;
+pub const ARENA_THRESHOLD: BufferAddress = 64 * 1024;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct FreeList {
+    capacity: BufferAddress,
+    free: Vec<Range<BufferAddress>>,
+}
+
+impl FreeList {
+    pub fn new(capacity: BufferAddress) -> Self {
+        Self {
+            capacity,
+            free: vec![Range {
+                start: 0,
+                end: capacity,
+            }],
+        }
+    }
+
+    pub fn allocate(&mut self, size: BufferAddress) -> Option<Range<BufferAddress>> {
+        let size = align(size);
+        let index = self
+            .free
+            .iter()
+            .position(|block| block.end - block.start >= size)?;
+
+        let start = self.free[index].start;
+        self.free[index].start += size;
+        if self.free[index].is_empty() {
+            self.free.remove(index);
+        }
+
+        Some(start..start + size)
+    }
+
+    pub fn free(&mut self, range: Range<BufferAddress>) {
+        let index = self.free.partition_point(|block| block.start < range.start);
+        self.free.insert(index, range);
+
+        if index + 1 < self.free.len() && self.free[index].end == self.free[index + 1].start {
+            self.free[index].end = self.free.remove(index + 1).end;
+        }
+        if index > 0 && self.free[index - 1].end == self.free[index].start {
+            self.free[index - 1].end = self.free.remove(index).end;
+        }
+    }
+
+    pub fn capacity(&self) -> BufferAddress {
+        self.capacity
+    }
+
+    pub fn used(&self) -> BufferAddress {
+        self.capacity
+            - self
+                .free
+                .iter()
+                .map(|block| block.end - block.start)
+                .sum::<BufferAddress>()
+    }
+
+    pub fn free_blocks(&self) -> usize {
+        self.free.len()
+    }
+
+    pub fn largest_free_block(&self) -> BufferAddress {
+        self.free
+            .iter()
+            .map(|block| block.end - block.start)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ArenaUtilization {
+    pub used: BufferAddress,
+    pub capacity: BufferAddress,
+    pub free_blocks: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct MemoryReport {
+    pub vertex_arena: ArenaUtilization,
+    pub index_arena: ArenaUtilization,
+    pub pending_releases: usize,
+}
+
+#[derive(Debug)]
+pub struct BufferArena {
+    buffer: Rc<Buffer>,
+    allocator: Rc<RefCell<FreeList>>,
+    threshold: BufferAddress,
+}
+
+impl BufferArena {
+    pub fn new(device: &Device, usage: BufferUsages, label: &str) -> Self {
+        let buffer = device.create_buffer(&BufferDescriptor {
+            label: Some(label),
+            size: ARENA_SIZE,
+            usage: usage | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            buffer: Rc::new(buffer),
+            allocator: Rc::new(RefCell::new(FreeList::new(ARENA_SIZE))),
+            threshold: ARENA_THRESHOLD,
+        }
+    }
+
+    pub fn allocate(&self, queue: &Queue, contents: &[u8]) -> Option<ArenaAllocation> {
+        if !fits_arena(contents.len() as BufferAddress, self.threshold) {
+            return None;
+        }
+
+        let range = self
+            .allocator
+            .borrow_mut()
+            .allocate(contents.len() as BufferAddress)?;
+
+        queue.write_buffer(&self.buffer, range.start, &padded(contents));
+
+        Some(ArenaAllocation {
+            buffer: Rc::clone(&self.buffer),
+            allocator: Rc::clone(&self.allocator),
+            bounds: draw_bounds(&range, contents.len() as BufferAddress),
+            range,
+        })
+    }
+
+    pub fn utilization(&self) -> ArenaUtilization {
+        let allocator = self.allocator.borrow();
+
+        ArenaUtilization {
+            used: allocator.used(),
+            capacity: allocator.capacity(),
+            free_blocks: allocator.free_blocks(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ArenaAllocation {
+    buffer: Rc<Buffer>,
+    allocator: Rc<RefCell<FreeList>>,
+    range: Range<BufferAddress>,
+    bounds: Range<BufferAddress>,
+}
+
+impl Drop for ArenaAllocation {
+    fn drop(&mut self) {
+        self.allocator.borrow_mut().free(self.range.clone());
+    }
+}
+
+#[derive(Debug)]
+pub enum GpuBuffer {
+    // Holds its size, since wgpu doesn't report it.
+    Dedicated(Buffer, BufferAddress),
+    Shared(ArenaAllocation),
+}
+
+impl GpuBuffer {
+    // Shared slices start at the shape's offset, so draws keep a base vertex and first index of 0.
+    pub fn slice(&self) -> BufferSlice<'_> {
+        match self {
+            GpuBuffer::Dedicated(buffer, _) => buffer.slice(..),
+            GpuBuffer::Shared(allocation) => allocation.buffer.slice(allocation.bounds.clone()),
+        }
+    }
+
+    pub fn capacity(&self) -> BufferAddress {
+        match self {
+            GpuBuffer::Dedicated(_, size) => *size,
+            GpuBuffer::Shared(allocation) => allocation.range.end - allocation.range.start,
+        }
+    }
+
+    // Overwrites the contents in place, or returns false when they no longer fit.
+    pub fn write(&mut self, queue: &Queue, contents: &[u8]) -> bool {
+        let padded = padded(contents);
+        if padded.len() as BufferAddress > self.capacity() {
+            return false;
+        }
+
+        match self {
+            GpuBuffer::Dedicated(buffer, _) => queue.write_buffer(buffer, 0, &padded),
+            GpuBuffer::Shared(allocation) => {
+                queue.write_buffer(&allocation.buffer, allocation.range.start, &padded);
+                allocation.bounds = draw_bounds(&allocation.range, contents.len() as BufferAddress);
+            }
+        }
+
+        true
+    }
+}
+
+impl Renderer {
+    pub fn memory_report(&self) -> MemoryReport {
+        MemoryReport {
+            vertex_arena: self.vertex_arena.utilization(),
+            index_arena: self.index_arena.utilization(),
+            pending_releases: self.pending_releases(),
+        }
+    }
+}
+
+pub fn align(size: BufferAddress) -> BufferAddress {
+    size.div_ceil(COPY_BUFFER_ALIGNMENT) * COPY_BUFFER_ALIGNMENT
+}
+
+// Writes have to cover whole words.
+pub fn padded(contents: &[u8]) -> Vec<u8> {
+    let mut padded = contents.to_vec();
+    padded.resize(align(contents.len() as BufferAddress) as usize, 0);
+
+    padded
+}
+
+pub fn fits_arena(size: BufferAddress, threshold: BufferAddress) -> bool {
+    size > 0 && size <= threshold
+}
+
+pub fn draw_bounds(range: &Range<BufferAddress>, size: BufferAddress) -> Range<BufferAddress> {
+    range.start..range.start + size
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::manifestation::pantry::{align, draw_bounds, fits_arena, padded, FreeList};
+
+    #[test]
+    fn allocations_are_aligned_and_contiguous() {
+        let mut free_list = FreeList::new(64);
+
+        assert_eq!(free_list.allocate(6), Some(0..8));
+        assert_eq!(free_list.allocate(12), Some(8..20));
+        assert_eq!(free_list.used(), 20);
+        assert_eq!(free_list.free_blocks(), 1);
+    }
+
+    #[test]
+    fn allocation_fails_when_no_block_is_large_enough() {
+        let mut free_list = FreeList::new(16);
+
+        assert_eq!(free_list.allocate(12), Some(0..12));
+        assert_eq!(free_list.allocate(8), None);
+        assert_eq!(free_list.allocate(4), Some(12..16));
+        assert_eq!(free_list.allocate(4), None);
+    }
+
+    #[test]
+    fn freeing_a_middle_block_leaves_a_hole() {
+        let mut free_list = FreeList::new(48);
+
+        let _first = free_list.allocate(16).unwrap();
+        let second = free_list.allocate(16).unwrap();
+        let _third = free_list.allocate(16).unwrap();
+        free_list.free(second);
+
+        assert_eq!(free_list.used(), 32);
+        assert_eq!(free_list.free_blocks(), 1);
+        assert_eq!(free_list.allocate(8), Some(16..24));
+    }
+
+    #[test]
+    fn freeing_neighbours_coalesces_blocks() {
+        let mut free_list = FreeList::new(64);
+
+        let first = free_list.allocate(16).unwrap();
+        let second = free_list.allocate(16).unwrap();
+        let third = free_list.allocate(16).unwrap();
+
+        free_list.free(first);
+        free_list.free(third);
+        assert_eq!(free_list.free_blocks(), 2);
+
+        free_list.free(second);
+        assert_eq!(free_list, FreeList::new(64));
+    }
+
+    #[test]
+    fn fragmentation_limits_the_largest_allocation() {
+        let mut free_list = FreeList::new(64);
+
+        let blocks = (0..8)
+            .map(|_| free_list.allocate(8).unwrap())
+            .collect::<Vec<_>>();
+        for block in blocks.into_iter().step_by(2) {
+            free_list.free(block);
+        }
+
+        assert_eq!(free_list.used(), 32);
+        assert_eq!(free_list.largest_free_block(), 8);
+        assert_eq!(free_list.allocate(16), None);
+        assert_eq!(free_list.allocate(8), Some(0..8));
+    }
+
+    #[test]
+    fn only_small_non_empty_buffers_go_to_the_arena() {
+        assert!(fits_arena(4, 1024));
+        assert!(fits_arena(1024, 1024));
+        assert!(!fits_arena(1025, 1024));
+        assert!(!fits_arena(0, 1024));
+    }
+
+    #[test]
+    fn draw_bounds_start_at_the_offset_and_skip_padding() {
+        let mut free_list = FreeList::new(64);
+
+        let _vertices = free_list.allocate(24).unwrap();
+        let indices = free_list.allocate(3 * 2).unwrap();
+
+        assert_eq!(indices, 24..32);
+        assert_eq!(draw_bounds(&indices, 3 * 2), 24..30);
+    }
+
+    #[test]
+    fn sizes_are_rounded_up_to_the_copy_alignment() {
+        assert_eq!(align(0), 0);
+        assert_eq!(align(1), 4);
+        assert_eq!(align(4), 4);
+        assert_eq!(align(6), 8);
+    }
+
+    #[test]
+    fn written_contents_are_padded_to_whole_words() {
+        assert_eq!(padded(&[1, 2, 3, 4, 5, 6]), vec![1, 2, 3, 4, 5, 6, 0, 0]);
+        assert_eq!(padded(&[1, 2, 3, 4]), vec![1, 2, 3, 4]);
+        assert_eq!(padded(&[]), Vec::<u8>::new());
+    }
+}
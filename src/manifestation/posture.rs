@@ -0,0 +1,286 @@
+use std::cell::Cell;
+
+use bytemuck::{Pod, Zeroable};
+use cgmath::{Matrix4, Rad, SquareMatrix, Vector3};
+use wgpu::{
+    util::{BufferInitDescriptor, DeviceExt},
+    BindGroup, BindGroupDescriptor, BindGroupEntry, BindGroupLayout, BindGroupLayoutDescriptor,
+    BindGroupLayoutEntry, BindingType, Buffer, BufferBindingType, BufferUsages, Device,
+    ShaderStages,
+};
+
+use crate::{life::Scene, manifestation::silhouette::Renderable, Engine};
+
+// Rotation is in radians, applied around X, then Y, then Z. Scale is applied first and translation last.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Transform {
+    pub position: [f32; 3],
+    pub rotation: [f32; 3],
+    pub scale: [f32; 3],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            position: [0.0; 3],
+            rotation: [0.0; 3],
+            scale: [1.0; 3],
+        }
+    }
+}
+
+impl Transform {
+    pub fn from_position(position: [f32; 3]) -> Self {
+        Self {
+            position,
+            ..Default::default()
+        }
+    }
+
+    pub fn model_matrix(&self) -> [[f32; 4]; 4] {
+        let [x, y, z] = self.rotation;
+        let [scale_x, scale_y, scale_z] = self.scale;
+
+        let model = Matrix4::from_translation(Vector3::from(self.position))
+            * Matrix4::from_angle_z(Rad(z))
+            * Matrix4::from_angle_y(Rad(y))
+            * Matrix4::from_angle_x(Rad(x))
+            * Matrix4::from_nonuniform_scale(scale_x, scale_y, scale_z);
+
+        model.into()
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ModelUniform {
+    pub model: [[f32; 4]; 4],
+}
+
+unsafe impl Zeroable for ModelUniform {}
+unsafe impl Pod for ModelUniform {}
+
+impl ModelUniform {
+    pub fn new(transform: Option<&Transform>) -> Self {
+        let model = match transform {
+            Some(transform) => transform.model_matrix(),
+            None => Matrix4::identity().into(),
+        };
+
+        Self { model }
+    }
+}
+
+// Every shape owns one of these, shaders read it through `@group(1) @binding(0)`.
+// `transformed` records whether the buffer last held a Transform rather than the identity.
+#[derive(Debug)]
+pub struct ModelBinding {
+    pub buffer: Buffer,
+    pub bind_group: BindGroup,
+    pub transformed: Cell<bool>,
+}
+
+impl ModelBinding {
+    pub fn new(device: &Device, layout: &BindGroupLayout) -> Self {
+        let buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("Model Buffer"),
+            contents: bytemuck::cast_slice(&[ModelUniform::new(None)]),
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        });
+
+        let bind_group = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("Model Bind Group"),
+            layout,
+            entries: &[BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+        });
+
+        Self {
+            buffer,
+            bind_group,
+            transformed: Cell::new(false),
+        }
+    }
+}
+
+pub fn model_layout(device: &Device) -> BindGroupLayout {
+    device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+        label: Some("Model Bind Group Layout"),
+        entries: &[BindGroupLayoutEntry {
+            binding: 0,
+            visibility: ShaderStages::VERTEX,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }],
+    })
+}
+
+impl Engine {
+    // Uploads the models of shapes whose Transform or renderable changed since the last frame, including
+    // changes the user already drained, and of shapes that lost their Transform.
+    pub fn update_transforms(&mut self) {
+        for scene in self.rendered_scenes() {
+            let shapes = match scene.try_get::<Box<dyn Renderable>>() {
+                Some(shapes) => shapes,
+                None => continue,
+            };
+
+            for (entity, shape) in shapes.iter_with_entities() {
+                let model = match shape.model() {
+                    Some(model) => model,
+                    None => continue,
+                };
+                if !model_outdated(scene, entity, model.transformed.get()) {
+                    continue;
+                }
+
+                let transform = scene.get_component::<Transform>(entity);
+                self.renderer.queue.write_buffer(
+                    &model.buffer,
+                    0,
+                    bytemuck::cast_slice(&[ModelUniform::new(transform)]),
+                );
+                model.transformed.set(transform.is_some());
+            }
+        }
+
+        for scene in self.rendered_scenes_mut() {
+            scene.forget_drained();
+        }
+    }
+}
+
+pub fn model_outdated(scene: &Scene, entity: usize, transformed: bool) -> bool {
+    scene.changed_since_render::<Box<dyn Renderable>>(entity)
+        || scene.changed_since_render::<Transform>(entity)
+        || transformed != scene.has::<Transform>(entity)
+}
+
+#[cfg(test)]
+mod tests {
+    use cgmath::{Matrix4, SquareMatrix, Vector4};
+
+    use wgpu::RenderPass;
+
+    use crate::{
+        life::Scene,
+        manifestation::{
+            easel::RenderContext,
+            lens::radians,
+            posture::{model_outdated, ModelUniform, Transform},
+            silhouette::Renderable,
+        },
+    };
+
+    struct Shape;
+
+    impl Renderable for Shape {
+        fn render<'a>(&'a self, _render_pass: &mut RenderPass<'a>, _context: &RenderContext<'a>) {}
+    }
+
+    // What the scene looks like right after `update_transforms` uploaded every model.
+    fn rendered_scene() -> (Scene, usize) {
+        let mut scene = Scene::new();
+        let entity = scene.entity().raw_id();
+        let shape: Box<dyn Renderable> = Box::new(Shape);
+        scene.component(entity, shape);
+        scene.component(entity, Transform::default());
+
+        scene.drain_changed::<Box<dyn Renderable>>();
+        scene.drain_changed::<Transform>();
+        scene.forget_drained();
+
+        (scene, entity)
+    }
+
+    fn apply(transform: &Transform, point: [f32; 3]) -> [f32; 3] {
+        let model = Matrix4::from(transform.model_matrix());
+        let moved = model * Vector4::new(point[0], point[1], point[2], 1.0);
+
+        [moved.x, moved.y, moved.z]
+    }
+
+    fn assert_close(left: [f32; 3], right: [f32; 3]) {
+        for (a, b) in left.iter().zip(right) {
+            assert!((a - b).abs() < 1e-5, "{:?} != {:?}", left, right);
+        }
+    }
+
+    #[test]
+    fn default_transform_is_the_identity() {
+        let identity: [[f32; 4]; 4] = Matrix4::identity().into();
+
+        assert_eq!(Transform::default().model_matrix(), identity);
+        assert_eq!(ModelUniform::new(None).model, identity);
+    }
+
+    #[test]
+    fn translation_moves_points() {
+        let transform = Transform::from_position([1.0, 2.0, 3.0]);
+
+        assert_close(apply(&transform, [1.0, 1.0, 1.0]), [2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn scale_is_applied_before_rotation_and_translation() {
+        let transform = Transform {
+            position: [10.0, 0.0, 0.0],
+            rotation: [0.0, 0.0, radians(90.0)],
+            scale: [2.0, 1.0, 1.0],
+        };
+
+        assert_close(apply(&transform, [1.0, 0.0, 0.0]), [10.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn rotations_apply_around_x_then_y_then_z() {
+        let transform = Transform {
+            rotation: [radians(90.0), radians(90.0), 0.0],
+            ..Default::default()
+        };
+
+        // +Y turns to +Z around X, then +Z turns to +X around Y.
+        assert_close(apply(&transform, [0.0, 1.0, 0.0]), [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn untouched_models_are_not_uploaded_again() {
+        let (scene, entity) = rendered_scene();
+
+        assert!(!model_outdated(&scene, entity, true));
+    }
+
+    #[test]
+    fn transforms_drained_before_rendering_are_still_uploaded() {
+        let (mut scene, entity) = rendered_scene();
+
+        // A system or the loop closure moves the shape, then drains, all before the redraw.
+        scene
+            .get_component_mut::<Transform>(entity)
+            .unwrap()
+            .position = [1.0, 0.0, 0.0];
+        scene.drain_changed::<Transform>();
+
+        assert!(model_outdated(&scene, entity, true));
+
+        scene.forget_drained();
+
+        assert!(!model_outdated(&scene, entity, true));
+    }
+
+    #[test]
+    fn removing_a_transform_resets_the_model() {
+        let (mut scene, entity) = rendered_scene();
+
+        scene.remove_component::<Transform>(entity);
+
+        assert!(model_outdated(&scene, entity, true));
+        assert!(!model_outdated(&scene, entity, false));
+    }
+}
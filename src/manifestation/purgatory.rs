@@ -0,0 +1,296 @@
+use std::{any::Any, cell::RefCell, collections::VecDeque, mem, mem::ManuallyDrop, rc::Rc};
+
+use wgpu::RenderPass;
+
+use crate::manifestation::{
+    easel::RenderContext,
+    posture::ModelBinding,
+    silhouette::{doritos::Doritos, platter::Batch, Renderable},
+    Renderer,
+};
+
+pub const FRAMES_IN_FLIGHT: u64 = 2;
+
+pub struct ReleaseQueue<T> {
+    frame: u64,
+    frames_in_flight: u64,
+
+    pending: VecDeque<(u64, T)>,
+}
+
+impl<T> ReleaseQueue<T> {
+    pub fn new(frames_in_flight: u64) -> Self {
+        Self {
+            frame: 0,
+            frames_in_flight,
+
+            pending: VecDeque::new(),
+        }
+    }
+
+    pub fn release(&mut self, resource: T) {
+        self.pending.push_back((self.frame, resource));
+    }
+
+    pub fn advance_frame(&mut self) {
+        self.frame += 1;
+
+        while let Some(&(released_at, _)) = self.pending.front() {
+            if released_at + self.frames_in_flight > self.frame {
+                break;
+            }
+
+            self.pending.pop_front();
+        }
+    }
+
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+// Shapes handed out by the renderer bury themselves here when they are dropped, wherever that happens:
+// deleted entities, removed components, cleared or popped scenes. The renderer moves them into its
+// release queue once per frame.
+#[derive(Clone, Default)]
+pub struct Graveyard {
+    buried: Rc<RefCell<Vec<Box<dyn Any>>>>,
+}
+
+impl Graveyard {
+    pub fn bury(&self, resource: Box<dyn Any>) {
+        self.buried.borrow_mut().push(resource);
+    }
+
+    pub fn exhume(&self) -> Vec<Box<dyn Any>> {
+        mem::take(&mut *self.buried.borrow_mut())
+    }
+
+    pub fn len(&self) -> usize {
+        self.buried.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buried.borrow().is_empty()
+    }
+}
+
+pub struct Tracked<T: 'static> {
+    shape: ManuallyDrop<T>,
+    graveyard: Graveyard,
+}
+
+impl<T: 'static> Tracked<T> {
+    pub fn new(shape: T, graveyard: Graveyard) -> Self {
+        Self {
+            shape: ManuallyDrop::new(shape),
+            graveyard,
+        }
+    }
+}
+
+impl<T: 'static> Drop for Tracked<T> {
+    fn drop(&mut self) {
+        // SAFETY: the shape is taken exactly once, here, and never touched again.
+        let shape = unsafe { ManuallyDrop::take(&mut self.shape) };
+        self.graveyard.bury(Box::new(shape));
+    }
+}
+
+impl<T: Renderable + 'static> Renderable for Tracked<T> {
+    fn render<'a>(&'a self, render_pass: &mut RenderPass<'a>, context: &RenderContext<'a>) {
+        self.shape.render(render_pass, context);
+    }
+
+    fn model(&self) -> Option<&ModelBinding> {
+        self.shape.model()
+    }
+
+    fn reload_shader(&mut self, renderer: &mut Renderer) {
+        self.shape.reload_shader(renderer);
+    }
+
+    fn batch_mut(&mut self) -> Option<&mut Batch> {
+        self.shape.batch_mut()
+    }
+
+    fn doritos_mut(&mut self) -> Option<&mut Doritos> {
+        self.shape.doritos_mut()
+    }
+}
+
+impl Renderer {
+    pub fn track<T: Renderable + 'static>(&self, shape: T) -> Box<dyn Renderable> {
+        Box::new(Tracked::new(shape, self.graveyard.clone()))
+    }
+
+    pub fn release<T: 'static>(&mut self, resource: T) {
+        self.release_queue.release(Box::new(resource));
+    }
+
+    // Called once per submitted frame.
+    pub fn advance_release_queue(&mut self) {
+        for resource in self.graveyard.exhume() {
+            self.release_queue.release(resource);
+        }
+
+        self.release_queue.advance_frame();
+    }
+
+    pub fn pending_releases(&self) -> usize {
+        self.release_queue.len() + self.graveyard.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use std::any::Any;
+
+    use wgpu::RenderPass;
+
+    use crate::{
+        life::Scene,
+        manifestation::{
+            easel::RenderContext,
+            purgatory::{Graveyard, ReleaseQueue, Tracked},
+            silhouette::Renderable,
+        },
+    };
+
+    struct MockResource {
+        drops: Rc<Cell<u32>>,
+    }
+
+    impl Drop for MockResource {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    fn mock(drops: &Rc<Cell<u32>>) -> MockResource {
+        MockResource {
+            drops: drops.clone(),
+        }
+    }
+
+    impl Renderable for MockResource {
+        fn render<'a>(&'a self, _render_pass: &mut RenderPass<'a>, _context: &RenderContext<'a>) {}
+    }
+
+    fn tracked(drops: &Rc<Cell<u32>>, graveyard: &Graveyard) -> Box<dyn Renderable> {
+        Box::new(Tracked::new(mock(drops), graveyard.clone()))
+    }
+
+    fn scene_with_shapes(drops: &Rc<Cell<u32>>, graveyard: &Graveyard) -> Scene {
+        let mut scene = Scene::new();
+
+        for _ in 0..3 {
+            let entity = scene.entity();
+            scene.component(entity, tracked(drops, graveyard));
+        }
+
+        scene
+    }
+
+    fn release_buried(queue: &mut ReleaseQueue<Box<dyn Any>>, graveyard: &Graveyard) {
+        for resource in graveyard.exhume() {
+            queue.release(resource);
+        }
+    }
+
+    #[test]
+    fn released_resources_are_held_for_the_frames_in_flight() {
+        let drops = Rc::new(Cell::new(0));
+        let mut queue = ReleaseQueue::new(2);
+
+        queue.release(mock(&drops));
+
+        queue.advance_frame();
+        assert_eq!(drops.get(), 0);
+        assert_eq!(queue.len(), 1);
+
+        queue.advance_frame();
+        assert_eq!(drops.get(), 1);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn resources_released_on_different_frames_are_freed_in_order() {
+        let drops = Rc::new(Cell::new(0));
+        let mut queue = ReleaseQueue::new(2);
+
+        queue.release(mock(&drops));
+        queue.advance_frame();
+        queue.release(mock(&drops));
+        queue.release(mock(&drops));
+
+        queue.advance_frame();
+        assert_eq!(drops.get(), 1);
+        assert_eq!(queue.len(), 2);
+
+        queue.advance_frame();
+        assert_eq!(drops.get(), 3);
+        assert_eq!(queue.frame(), 3);
+    }
+
+    #[test]
+    fn a_single_frame_in_flight_frees_on_the_next_frame() {
+        let drops = Rc::new(Cell::new(0));
+        let mut queue = ReleaseQueue::new(1);
+
+        queue.release(mock(&drops));
+        assert_eq!(drops.get(), 0);
+
+        queue.advance_frame();
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn every_way_a_scene_drops_a_shape_buries_it() {
+        let drops = Rc::new(Cell::new(0));
+        let graveyard = Graveyard::default();
+        let mut scene = scene_with_shapes(&drops, &graveyard);
+
+        scene.delete(0);
+        drop(scene.remove_component::<Box<dyn Renderable>>(1));
+        scene.clear_components::<Box<dyn Renderable>>();
+        assert_eq!(graveyard.len(), 3);
+
+        let mut scene = scene_with_shapes(&drops, &graveyard);
+        scene.clear();
+        assert_eq!(graveyard.len(), 6);
+
+        drop(scene_with_shapes(&drops, &graveyard));
+        assert_eq!(graveyard.len(), 9);
+        assert_eq!(drops.get(), 0);
+    }
+
+    #[test]
+    fn buried_shapes_are_freed_after_the_frames_in_flight() {
+        let drops = Rc::new(Cell::new(0));
+        let graveyard = Graveyard::default();
+        let mut queue = ReleaseQueue::new(2);
+        let mut scene = scene_with_shapes(&drops, &graveyard);
+
+        scene.delete(1);
+        release_buried(&mut queue, &graveyard);
+
+        queue.advance_frame();
+        assert_eq!(drops.get(), 0);
+        assert!(graveyard.is_empty());
+
+        queue.advance_frame();
+        assert_eq!(drops.get(), 1);
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,376 @@
+use std::{error::Error, fmt, ops::Range};
+
+use naga::{
+    front::wgsl,
+    valid::{Capabilities, ValidationFlags, Validator},
+    Module,
+};
+use wgpu::{RenderPipeline, ShaderModule, ShaderModuleDescriptor, ShaderSource};
+
+use crate::{
+    calamity::IgnitionError,
+    manifestation::{apex::VertexGroup, Renderer},
+    Engine,
+};
+
+// (group, binding) pairs every pipeline layout provides; group 0 is the camera uniform and group 1 the model matrix.
+pub const PIPELINE_BINDINGS: &[(u32, u32)] = &[(0, 0), (1, 0)];
+
+// Lines of source shown above the offending line.
+const CONTEXT_LINES: usize = 2;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ShaderDiagnostics {
+    pub label: String,
+    pub pipeline: Option<String>,
+    pub message: String,
+
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+}
+
+impl ShaderDiagnostics {
+    pub fn new(
+        label: &str,
+        pipeline: Option<&str>,
+        source: &str,
+        span: Option<Range<usize>>,
+        message: String,
+        annotation: &str,
+    ) -> Self {
+        let (line, column, snippet) = match span {
+            Some(span) if span.start <= source.len() => annotate(source, span, annotation),
+            _ => (0, 0, String::new()),
+        };
+
+        Self {
+            label: label.to_string(),
+            pipeline: pipeline.map(str::to_string),
+            message,
+
+            line,
+            column,
+            snippet,
+        }
+    }
+}
+
+impl fmt::Display for ShaderDiagnostics {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(formatter, "error: {}", self.message)?;
+        write!(formatter, "  --> shader `{}`", self.label)?;
+        if let Some(pipeline) = &self.pipeline {
+            write!(formatter, " (requested by pipeline `{}`)", pipeline)?;
+        }
+        if self.line > 0 {
+            write!(formatter, " at {}:{}", self.line, self.column)?;
+        }
+        writeln!(formatter)?;
+
+        write!(formatter, "{}", self.snippet)
+    }
+}
+
+pub fn validate_shader(
+    label: &str,
+    pipeline: Option<&str>,
+    source: &str,
+) -> Result<Module, ShaderDiagnostics> {
+    let module = wgsl::parse_str(source).map_err(|error| {
+        let (span, annotation) = error
+            .labels()
+            .next()
+            .map_or((None, ""), |(span, annotation)| (Some(span), annotation));
+
+        ShaderDiagnostics::new(
+            label,
+            pipeline,
+            source,
+            span,
+            error.message().to_string(),
+            annotation,
+        )
+    })?;
+
+    Validator::new(ValidationFlags::all(), Capabilities::empty())
+        .validate(&module)
+        .map_err(|error| {
+            let (span, annotation) = error
+                .spans()
+                .next()
+                .map_or((None, ""), |(span, annotation)| {
+                    (span.to_range(), annotation.as_str())
+                });
+
+            ShaderDiagnostics::new(
+                label,
+                pipeline,
+                source,
+                span,
+                error_chain(error.as_inner()),
+                annotation,
+            )
+        })?;
+
+    Ok(module)
+}
+
+pub fn check_bindings(
+    label: &str,
+    pipeline: Option<&str>,
+    source: &str,
+    module: &Module,
+    provided: &[(u32, u32)],
+) -> Result<(), ShaderDiagnostics> {
+    for (handle, variable) in module.global_variables.iter() {
+        let binding = match &variable.binding {
+            Some(binding) => binding,
+            None => continue,
+        };
+
+        if !provided.contains(&(binding.group, binding.binding)) {
+            return Err(ShaderDiagnostics::new(
+                label,
+                pipeline,
+                source,
+                module.global_variables.get_span(handle).to_range(),
+                format!(
+                    "@group({}) @binding({}) is not provided by the pipeline layout",
+                    binding.group, binding.binding
+                ),
+                "missing binding",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+impl Renderer {
+    pub fn create_shader(&self, label: &str, source: &str) -> Result<ShaderModule, IgnitionError> {
+        validate_shader(label, None, source)?;
+
+        Ok(self.shader_module(label, source))
+    }
+
+    pub fn pipeline_for(
+        &mut self,
+        name: &str,
+        vertex_group: &VertexGroup,
+        label: &str,
+        source: &str,
+    ) -> Result<RenderPipeline, IgnitionError> {
+        let module = validate_shader(label, Some(name), source)?;
+        check_bindings(label, Some(name), source, &module, PIPELINE_BINDINGS)?;
+
+        let shader = self.shader_module(label, source);
+        Ok(self.pipeline_from_module(vertex_group, &shader))
+    }
+
+    fn shader_module(&self, label: &str, source: &str) -> ShaderModule {
+        self.device.create_shader_module(ShaderModuleDescriptor {
+            label: Some(label),
+            source: ShaderSource::Wgsl(source.into()),
+        })
+    }
+}
+
+impl Engine {
+    pub fn create_shader(&self, label: &str, source: &str) -> Result<ShaderModule, IgnitionError> {
+        self.renderer.create_shader(label, source)
+    }
+
+    pub fn pipeline_for(
+        &mut self,
+        name: &str,
+        vertex_group: &VertexGroup,
+        label: &str,
+        source: &str,
+    ) -> Result<RenderPipeline, IgnitionError> {
+        self.renderer
+            .pipeline_for(name, vertex_group, label, source)
+    }
+}
+
+fn error_chain(error: &dyn Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+
+    while let Some(error) = source {
+        message.push_str(": ");
+        message.push_str(&error.to_string());
+        source = error.source();
+    }
+
+    message
+}
+
+fn annotate(source: &str, span: Range<usize>, annotation: &str) -> (usize, usize, String) {
+    let line = source[..span.start].matches('\n').count() + 1;
+    let line_start = source[..span.start]
+        .rfind('\n')
+        .map_or(0, |index| index + 1);
+    let column = source[line_start..span.start].chars().count() + 1;
+
+    let lines: Vec<&str> = source.lines().collect();
+    let first = line.saturating_sub(CONTEXT_LINES).max(1);
+    let last = (line + 1).min(lines.len().max(line));
+    let width = last.to_string().len();
+
+    let line_end = source[span.start..]
+        .find('\n')
+        .map_or(source.len(), |index| span.start + index);
+    let carets = source[span.start..span.end.min(line_end)]
+        .chars()
+        .count()
+        .max(1);
+
+    let mut snippet = format!("{:width$} |\n", "", width = width);
+    for number in first..=last {
+        let text = lines.get(number - 1).copied().unwrap_or("");
+        let source_line = format!("{:>width$} | {}", number, text, width = width);
+        snippet.push_str(source_line.trim_end());
+        snippet.push('\n');
+
+        if number == line {
+            snippet.push_str(&format!(
+                "{:width$} | {}{} {}\n",
+                "",
+                " ".repeat(column - 1),
+                "^".repeat(carets),
+                annotation,
+                width = width
+            ));
+        }
+    }
+
+    (line, column, snippet)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::manifestation::scripture::{
+        check_bindings, validate_shader, ShaderDiagnostics, PIPELINE_BINDINGS,
+    };
+
+    const BAD_TYPE: &str = "@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    let brightness: f32 = 1u;
+    return vec4<f32>(brightness);
+}
+";
+
+    const SYNTAX_ERROR: &str = "@vertex
+fn vs_main() -> @builtin(position) vec4<f32> {
+    return vec4<f32>(0.0, 0.0, 0.0, 1.0)
+}
+";
+
+    const MISSING_BINDING: &str = "struct Light {
+    color: vec4<f32>,
+};
+
+@group(2) @binding(0)
+var<uniform> light: Light;
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return light.color;
+}
+";
+
+    const UNDEFINED_FUNCTION: &str = "@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return glow();
+}
+";
+
+    fn diagnose_or_panic(source: &str) -> ShaderDiagnostics {
+        match validate_shader("lighting", Some("sprites"), source) {
+            Ok(module) => check_bindings(
+                "lighting",
+                Some("sprites"),
+                source,
+                &module,
+                PIPELINE_BINDINGS,
+            )
+            .expect_err("shader should not validate"),
+            Err(diagnostics) => diagnostics,
+        }
+    }
+
+    #[test]
+    fn type_mismatches_point_at_the_offending_line() {
+        let diagnostics = diagnose_or_panic(BAD_TYPE);
+        let report = diagnostics.to_string();
+
+        assert_eq!(diagnostics.line, 3);
+        assert!(
+            report.contains("3 |     let brightness: f32 = 1u;"),
+            "{}",
+            report
+        );
+        assert!(report.contains("^"), "{}", report);
+    }
+
+    #[test]
+    fn syntax_errors_point_at_where_parsing_stopped() {
+        let diagnostics = diagnose_or_panic(SYNTAX_ERROR);
+        let report = diagnostics.to_string();
+
+        assert_eq!(diagnostics.line, 4);
+        assert!(
+            report.contains("3 |     return vec4<f32>(0.0, 0.0, 0.0, 1.0)"),
+            "{}",
+            report
+        );
+    }
+
+    #[test]
+    fn missing_bindings_point_at_the_declaration() {
+        let diagnostics = diagnose_or_panic(MISSING_BINDING);
+        let report = diagnostics.to_string();
+
+        assert_eq!(diagnostics.line, 6);
+        assert!(
+            report.contains("@group(2) @binding(0) is not provided"),
+            "{}",
+            report
+        );
+        assert!(
+            report.contains("6 | var<uniform> light: Light;"),
+            "{}",
+            report
+        );
+        assert!(report.contains("missing binding"), "{}", report);
+    }
+
+    #[test]
+    fn undefined_functions_are_reported() {
+        let diagnostics = diagnose_or_panic(UNDEFINED_FUNCTION);
+
+        assert_eq!(diagnostics.line, 3);
+        assert!(diagnostics.snippet.contains("return glow();"));
+    }
+
+    #[test]
+    fn reports_name_the_shader_and_the_pipeline() {
+        let report = diagnose_or_panic(BAD_TYPE).to_string();
+
+        assert!(
+            report.contains("  --> shader `lighting` (requested by pipeline `sprites`) at 3:"),
+            "{}",
+            report
+        );
+        assert!(report.starts_with("error: "));
+    }
+
+    #[test]
+    fn valid_shaders_pass() {
+        let source = include_str!("shaders/grid.wgsl");
+        let module = validate_shader("grid", None, source).unwrap();
+
+        assert!(check_bindings("grid", None, source, &module, PIPELINE_BINDINGS).is_ok());
+    }
+}
@@ -0,0 +1,165 @@
+use std::f32::consts::TAU;
+
+use wgpu::VertexFormat;
+
+use crate::{manifestation::apex::IndexData, Engine};
+
+impl Engine {
+    pub fn circle(&mut self, center: [f32; 2], radius: f32, segments: u32) -> &mut Self {
+        let (vertices, indices) = circle(center, radius, segments);
+
+        self.data_slice(&vertices, 2, VertexFormat::Float32x2)
+            .indices(indices)
+    }
+}
+
+pub fn circle(center: [f32; 2], radius: f32, segments: u32) -> (Vec<f32>, IndexData) {
+    debug_assert!(
+        segments >= 3,
+        "Error: A circle needs at least 3 segments, got {} - Ignition",
+        segments
+    );
+
+    let mut vertices = Vec::with_capacity(2 * (segments as usize + 1));
+    vertices.extend_from_slice(&center);
+
+    for segment in 0..segments {
+        let angle = TAU * segment as f32 / segments as f32;
+
+        vertices.push(center[0] + radius * angle.cos());
+        vertices.push(center[1] + radius * angle.sin());
+    }
+
+    debug_assert!(
+        vertices.iter().all(|value| (-1.0..=1.0).contains(value)),
+        "Error: Circle vertices must lie within [-1, 1] - Ignition"
+    );
+
+    let indices: Vec<u32> = (1..=segments)
+        .flat_map(|segment| [0, segment, segment % segments + 1])
+        .collect();
+
+    let indices = if segments < u16::MAX as u32 {
+        IndexData::U16(indices.into_iter().map(|index| index as u16).collect())
+    } else {
+        IndexData::U32(indices)
+    };
+
+    (vertices, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::manifestation::{apex::IndexData, sculpture::circle};
+
+    fn rim(vertices: &[f32]) -> Vec<[f32; 2]> {
+        vertices[2..]
+            .chunks(2)
+            .map(|point| [point[0], point[1]])
+            .collect()
+    }
+
+    fn u16_indices(indices: IndexData) -> Vec<u16> {
+        match indices {
+            IndexData::U16(indices) => indices,
+            IndexData::U32(_) => panic!("expected u16 indices"),
+        }
+    }
+
+    #[test]
+    fn circle_has_one_vertex_per_segment_plus_the_center() {
+        let (vertices, _indices) = circle([0.0, 0.0], 0.5, 12);
+
+        assert_eq!(vertices.len(), 2 * 13);
+    }
+
+    #[test]
+    fn circle_starts_with_the_center_vertex() {
+        let (vertices, _indices) = circle([0.25, -0.25], 0.5, 8);
+
+        assert_eq!(&vertices[..2], &[0.25, -0.25]);
+    }
+
+    #[test]
+    fn circle_rim_vertices_lie_at_the_radius() {
+        let (vertices, _indices) = circle([0.1, 0.2], 0.5, 16);
+
+        for [x, y] in rim(&vertices) {
+            let distance = ((x - 0.1).powi(2) + (y - 0.2).powi(2)).sqrt();
+            assert!((distance - 0.5).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn circle_first_rim_vertex_points_along_positive_x() {
+        let (vertices, _indices) = circle([0.0, 0.0], 0.5, 4);
+
+        assert_eq!(rim(&vertices)[0], [0.5, 0.0]);
+    }
+
+    #[test]
+    fn circle_rim_vertices_wind_counter_clockwise() {
+        let (vertices, _indices) = circle([0.0, 0.0], 0.5, 4);
+        let rim = rim(&vertices);
+
+        assert!(rim[1][1] > 0.0);
+        assert!(rim[3][1] < 0.0);
+    }
+
+    #[test]
+    fn circle_has_three_indices_per_segment() {
+        let (_vertices, indices) = circle([0.0, 0.0], 0.5, 10);
+
+        assert_eq!(indices.len(), 30);
+    }
+
+    #[test]
+    fn circle_triangles_all_share_the_center_vertex() {
+        let (_vertices, indices) = circle([0.0, 0.0], 0.5, 10);
+
+        for triangle in u16_indices(indices).chunks(3) {
+            assert_eq!(triangle[0], 0);
+        }
+    }
+
+    #[test]
+    fn circle_last_triangle_wraps_back_to_the_first_rim_vertex() {
+        let (_vertices, indices) = circle([0.0, 0.0], 0.5, 3);
+
+        assert_eq!(u16_indices(indices), vec![0, 1, 2, 0, 2, 3, 0, 3, 1]);
+    }
+
+    #[test]
+    fn circle_indices_stay_within_the_vertex_count() {
+        let (vertices, indices) = circle([0.0, 0.0], 0.5, 32);
+        let num_vertices = (vertices.len() / 2) as u16;
+
+        assert!(u16_indices(indices)
+            .iter()
+            .all(|&index| index < num_vertices));
+    }
+
+    #[test]
+    fn circle_with_many_segments_switches_to_u32_indices() {
+        let (_vertices, small) = circle([0.0, 0.0], 0.5, 64);
+        let (_vertices, large) = circle([0.0, 0.0], 0.5, 70_000);
+
+        assert_eq!(small.format(), wgpu::IndexFormat::Uint16);
+        assert_eq!(large.format(), wgpu::IndexFormat::Uint32);
+        assert_eq!(large.len(), 3 * 70_000);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Error: Circle vertices must lie within [-1, 1] - Ignition")]
+    fn circle_outside_of_clip_space_panics_in_debug() {
+        circle([0.8, 0.0], 0.5, 8);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Error: A circle needs at least 3 segments, got 2 - Ignition")]
+    fn circle_with_too_few_segments_panics_in_debug() {
+        circle([0.0, 0.0], 0.5, 2);
+    }
+}
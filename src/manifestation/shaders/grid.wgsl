@@ -0,0 +1,91 @@
+// Infinite grid on the z = 0 plane, mirrored on the CPU by manifestation::lattice
+
+struct Grid {
+    inverse_view_projection: mat4x4<f32>,
+    color_minor: vec4<f32>,
+    color_major: vec4<f32>,
+    color_axes: vec4<f32>,
+    base_spacing: f32,
+    subdivisions: f32,
+    line_width: f32,
+    min_pixel_spacing: f32,
+};
+
+@group(0) @binding(0)
+var<uniform> grid: Grid;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) ndc: vec2<f32>,
+};
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    let ndc = uv * 2.0 - 1.0;
+
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(ndc, 1.0, 1.0);
+    out.ndc = ndc;
+    return out;
+}
+
+fn unproject(ndc: vec2<f32>, depth: f32) -> vec3<f32> {
+    let unprojected = grid.inverse_view_projection * vec4<f32>(ndc, depth, 1.0);
+    return unprojected.xyz / unprojected.w;
+}
+
+fn line_coverage(coordinate: f32, spacing: f32, world_per_pixel: f32) -> f32 {
+    let cell = coordinate / spacing;
+    let distance = abs(cell - round(cell)) * spacing / world_per_pixel;
+    return clamp(grid.line_width * 0.5 + 0.5 - distance, 0.0, 1.0);
+}
+
+fn level_coverage(position: vec2<f32>, spacing: f32, world_per_pixel: f32) -> f32 {
+    return max(
+        line_coverage(position.x, spacing, world_per_pixel),
+        line_coverage(position.y, spacing, world_per_pixel),
+    );
+}
+
+fn level_color(level: f32) -> vec4<f32> {
+    if (level <= 0.0) {
+        return vec4<f32>(grid.color_minor.rgb, grid.color_minor.a * (1.0 + level));
+    }
+    return mix(grid.color_minor, grid.color_major, min(level, 1.0));
+}
+
+fn over(below: vec4<f32>, above: vec4<f32>, coverage: f32) -> vec4<f32> {
+    return mix(below, above, coverage);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let near = unproject(in.ndc, 0.0);
+    let far = unproject(in.ndc, 1.0);
+    let t = -near.z / (far.z - near.z);
+
+    let position = (near + (far - near) * t).xy;
+    let world_per_pixel = max(max(fwidth(position.x), fwidth(position.y)), 1e-12);
+
+    let lod = log(world_per_pixel * grid.min_pixel_spacing / grid.base_spacing) / log(grid.subdivisions);
+    let fade = fract(lod);
+    let spacing = grid.base_spacing * pow(grid.subdivisions, floor(lod));
+
+    var color = vec4<f32>(0.0);
+    color = over(color, level_color(-fade), level_coverage(position, spacing, world_per_pixel));
+    color = over(color, level_color(1.0 - fade), level_coverage(position, spacing * grid.subdivisions, world_per_pixel));
+    color = over(color, level_color(2.0 - fade), level_coverage(position, spacing * grid.subdivisions * grid.subdivisions, world_per_pixel));
+
+    let axes = max(
+        clamp(grid.line_width * 0.5 + 0.5 - abs(position.x) / world_per_pixel, 0.0, 1.0),
+        clamp(grid.line_width * 0.5 + 0.5 - abs(position.y) / world_per_pixel, 0.0, 1.0),
+    );
+    color = over(color, grid.color_axes, axes);
+
+    // Derivatives need uniform control flow, so misses are only discarded at the end.
+    if (t < 0.0 || far.z == near.z || color.a <= 0.0) {
+        discard;
+    }
+    return color;
+}
@@ -0,0 +1,45 @@
+// Textured quad drawn by manifestation::silhouette::sprite
+
+struct Camera {
+    view_projection: mat4x4<f32>,
+};
+
+struct Model {
+    model: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> camera: Camera;
+
+@group(1) @binding(0)
+var<uniform> model: Model;
+
+@group(2) @binding(0)
+var sprite_texture: texture_2d<f32>;
+@group(2) @binding(1)
+var sprite_sampler: sampler;
+
+var<push_constant> tint: vec4<f32>;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) uv: vec2<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+@vertex
+fn vs_main(vertex: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = camera.view_projection * model.model * vec4<f32>(vertex.position, 1.0);
+    out.uv = vertex.uv;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(sprite_texture, sprite_sampler, in.uv) * tint;
+}
@@ -1,8 +1,31 @@
 use wgpu::RenderPass;
 
+use crate::manifestation::{
+    easel::RenderContext,
+    posture::ModelBinding,
+    silhouette::{doritos::Doritos, platter::Batch},
+    Renderer,
+};
+
 pub mod crackers;
 pub mod doritos;
+pub mod platter;
+pub mod sprite;
 
 pub trait Renderable {
-    fn render<'a>(&'a self, render_pass: &mut RenderPass<'a>);
+    fn render<'a>(&'a self, render_pass: &mut RenderPass<'a>, context: &RenderContext<'a>);
+
+    fn model(&self) -> Option<&ModelBinding> {
+        None
+    }
+
+    fn reload_shader(&mut self, _renderer: &mut Renderer) {}
+
+    fn batch_mut(&mut self) -> Option<&mut Batch> {
+        None
+    }
+
+    fn doritos_mut(&mut self) -> Option<&mut Doritos> {
+        None
+    }
 }
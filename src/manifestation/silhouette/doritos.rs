@@ -1,37 +1,154 @@
-use wgpu::{Buffer, RenderPass, RenderPipeline, ShaderModuleDescriptor};
+use std::mem;
 
-use crate::manifestation::{apex::VertexGroup, silhouette::Renderable, Renderer};
+use wgpu::{IndexFormat, RenderPass, RenderPipeline};
+
+use crate::{
+    life::genesis::entity::EntityKey,
+    manifestation::{
+        apex::{IndexData, VertexGroup},
+        easel::RenderContext,
+        metamorphosis::{ShaderSource, WatchedShader},
+        pantry::GpuBuffer,
+        posture::ModelBinding,
+        silhouette::Renderable,
+        Renderer,
+    },
+    Engine,
+};
 
 #[derive(Debug)]
 pub struct Doritos {
     pub pipeline: RenderPipeline,
-    pub vertex_buffer: Buffer,
+    pub vertex_buffer: GpuBuffer,
+    pub index_buffer: Option<IndexBuffer>,
+    pub model: ModelBinding,
+    pub shader: Option<WatchedShader>,
 
     pub num_vertices: u32,
 }
 
+#[derive(Debug)]
+pub struct IndexBuffer {
+    pub buffer: GpuBuffer,
+    pub format: IndexFormat,
+
+    pub num_indices: u32,
+}
+
 impl Renderer {
     pub fn doritos(
         &mut self,
         vertex_group: &VertexGroup,
-        shaders: ShaderModuleDescriptor,
+        shaders: impl Into<ShaderSource>,
     ) -> Box<dyn Renderable> {
+        let (pipeline, shader) = self.shader_pipeline(vertex_group, shaders.into());
         let doritos = Doritos {
-            pipeline: self.pipeline(vertex_group, shaders),
+            pipeline,
             vertex_buffer: self.vertex_buffer(vertex_group),
+            index_buffer: None,
+            model: ModelBinding::new(&self.device, &self.model_layout),
+            shader,
 
             num_vertices: vertex_group.num_vertices,
         };
 
-        Box::new(doritos)
+        self.track(doritos)
+    }
+
+    pub fn doritos_indexed(
+        &mut self,
+        vertex_group: &VertexGroup,
+        indices: &IndexData,
+        shaders: impl Into<ShaderSource>,
+    ) -> Box<dyn Renderable> {
+        let index_buffer = IndexBuffer {
+            buffer: self.index_buffer(indices),
+            format: indices.format(),
+
+            num_indices: indices.len() as u32,
+        };
+
+        let (pipeline, shader) = self.shader_pipeline(vertex_group, shaders.into());
+        let doritos = Doritos {
+            pipeline,
+            vertex_buffer: self.vertex_buffer(vertex_group),
+            index_buffer: Some(index_buffer),
+            model: ModelBinding::new(&self.device, &self.model_layout),
+            shader,
+
+            num_vertices: vertex_group.num_vertices,
+        };
+
+        self.track(doritos)
     }
 }
 
 impl Renderable for Doritos {
-    fn render<'a>(&'a self, render_pass: &mut RenderPass<'a>) {
+    fn render<'a>(&'a self, render_pass: &mut RenderPass<'a>, _context: &RenderContext<'a>) {
         render_pass.set_pipeline(&self.pipeline);
-        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.set_bind_group(1, &self.model.bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice());
+
+        match &self.index_buffer {
+            Some(index_buffer) => {
+                render_pass.set_index_buffer(index_buffer.buffer.slice(), index_buffer.format);
+                render_pass.draw_indexed(0..index_buffer.num_indices, 0, 0..1);
+            }
+            None => render_pass.draw(0..self.num_vertices, 0..1),
+        }
+    }
+
+    fn model(&self) -> Option<&ModelBinding> {
+        Some(&self.model)
+    }
+
+    fn reload_shader(&mut self, renderer: &mut Renderer) {
+        if let Some(pipeline) = self
+            .shader
+            .as_ref()
+            .and_then(|shader| shader.reload(renderer))
+        {
+            let old = mem::replace(&mut self.pipeline, pipeline);
+            renderer.release(old);
+        }
+    }
+
+    fn doritos_mut(&mut self) -> Option<&mut Doritos> {
+        Some(self)
+    }
+}
+
+impl Doritos {
+    // Keeps the pipeline, so the vertices must share the layout the shape was built with. The buffer is
+    // rewritten in place while the vertices fit and replaced once they outgrow it.
+    pub fn update_vertices(&mut self, renderer: &mut Renderer, vertex_group: &VertexGroup) {
+        if !self
+            .vertex_buffer
+            .write(&renderer.queue, &vertex_group.get())
+        {
+            let vertex_buffer = renderer.vertex_buffer(vertex_group);
+            let old = mem::replace(&mut self.vertex_buffer, vertex_buffer);
+            renderer.release(old);
+        }
+
+        self.num_vertices = vertex_group.num_vertices;
+    }
+}
+
+impl Engine {
+    // Returns false when the entity has no Doritos.
+    pub fn update_vertices(&mut self, entity: impl EntityKey, vertex_group: &VertexGroup) -> bool {
+        let doritos = self
+            .scene
+            .get_component_mut::<Box<dyn Renderable>>(entity)
+            .and_then(|shape| shape.doritos_mut());
 
-        render_pass.draw(0..self.num_vertices, 0..1);
+        match doritos {
+            Some(doritos) => {
+                doritos.update_vertices(&mut self.renderer, vertex_group);
+                true
+            }
+            None => false,
+        }
     }
 }
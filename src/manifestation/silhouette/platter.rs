@@ -0,0 +1,384 @@
+use std::collections::BTreeMap;
+
+use wgpu::{
+    Buffer, BufferAddress, BufferDescriptor, BufferUsages, IndexFormat, RenderPass, RenderPipeline,
+    COPY_BUFFER_ALIGNMENT,
+};
+
+use crate::{
+    life::{Entity, Scene},
+    manifestation::{
+        apex::{IndexData, VertexGroup},
+        easel::RenderContext,
+        metamorphosis::{ShaderSource, WatchedShader},
+        pantry::padded,
+        posture::ModelBinding,
+        silhouette::Renderable,
+        Renderer,
+    },
+    Engine,
+};
+
+// Marks a shape as drawn by the batch on the given entity. Disabling it hides the shape.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BatchMember(pub usize);
+
+// Geometry of every member is merged into one buffer pair each frame and drawn with a single call.
+// Members share the batch's pipeline and model matrix, their own transforms are not applied.
+pub struct Batch {
+    shaders: Option<ShaderSource>,
+    pipeline: Option<RenderPipeline>,
+    shader: Option<WatchedShader>,
+    layout: Option<VertexGroup>,
+    model: ModelBinding,
+
+    vertex_buffer: Option<(Buffer, BufferAddress)>,
+    index_buffer: Option<(Buffer, BufferAddress)>,
+    num_indices: u32,
+}
+
+impl Batch {
+    pub fn new(renderer: &Renderer, shaders: impl Into<ShaderSource>) -> Self {
+        Self {
+            shaders: Some(shaders.into()),
+            pipeline: None,
+            shader: None,
+            layout: None,
+            model: ModelBinding::new(&renderer.device, &renderer.model_layout),
+
+            vertex_buffer: None,
+            index_buffer: None,
+            num_indices: 0,
+        }
+    }
+
+    pub fn num_indices(&self) -> u32 {
+        self.num_indices
+    }
+
+    // The pipeline is built from the layout of the first geometry the batch receives.
+    pub fn upload(&mut self, renderer: &mut Renderer, geometry: Option<&MergedGeometry>) {
+        let geometry = match geometry {
+            Some(geometry) => geometry,
+            None => {
+                self.num_indices = 0;
+                return;
+            }
+        };
+
+        match &self.layout {
+            None => {
+                let shaders = self.shaders.take().unwrap();
+                let (pipeline, shader) = renderer.shader_pipeline(&geometry.layout, shaders);
+
+                self.pipeline = Some(pipeline);
+                self.shader = shader;
+                self.layout = Some(geometry.layout.layout_only());
+            }
+            Some(layout) if !same_layout(layout, &geometry.layout) => {
+                log::warn!("Skipping batch whose shapes changed their vertex layout");
+                self.num_indices = 0;
+                return;
+            }
+            Some(_) => (),
+        }
+
+        self.num_indices = geometry.indices.len() as u32;
+        stream(
+            renderer,
+            &mut self.vertex_buffer,
+            BufferUsages::VERTEX,
+            &geometry.vertices,
+        );
+        stream(
+            renderer,
+            &mut self.index_buffer,
+            BufferUsages::INDEX,
+            bytemuck::cast_slice(&geometry.indices),
+        );
+    }
+}
+
+impl Renderable for Batch {
+    fn render<'a>(&'a self, render_pass: &mut RenderPass<'a>, _context: &RenderContext<'a>) {
+        let (pipeline, (vertex_buffer, _), (index_buffer, _)) =
+            match (&self.pipeline, &self.vertex_buffer, &self.index_buffer) {
+                (Some(pipeline), Some(vertex_buffer), Some(index_buffer)) => {
+                    (pipeline, vertex_buffer, index_buffer)
+                }
+                _ => return,
+            };
+        if self.num_indices == 0 {
+            return;
+        }
+
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_bind_group(1, &self.model.bind_group, &[]);
+        render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+        render_pass.set_index_buffer(index_buffer.slice(..), IndexFormat::Uint32);
+        render_pass.draw_indexed(0..self.num_indices, 0, 0..1);
+    }
+
+    fn model(&self) -> Option<&ModelBinding> {
+        Some(&self.model)
+    }
+
+    fn reload_shader(&mut self, renderer: &mut Renderer) {
+        if let Some(pipeline) = self
+            .shader
+            .as_ref()
+            .and_then(|shader| shader.reload(renderer))
+        {
+            if let Some(old) = self.pipeline.replace(pipeline) {
+                renderer.release(old);
+            }
+        }
+    }
+
+    fn batch_mut(&mut self) -> Option<&mut Batch> {
+        Some(self)
+    }
+}
+
+impl Engine {
+    // Takes the shaders of the entity being built, like `doritos`, and returns that entity.
+    pub fn batch(&mut self) -> Entity {
+        let entity = self.building();
+        let shaders = self.take_shaders(entity);
+
+        let batch = self.renderer.track(Batch::new(&self.renderer, shaders));
+        self.scene.component(entity, batch);
+
+        self.entity()
+    }
+
+    // Keeps the vertices and indices of the entity being built on the CPU for `batch` to merge.
+    pub fn batched(&mut self, batch: Entity) -> Entity {
+        let entity = self.building();
+        self.scene.component(entity, BatchMember(batch.raw_id()));
+
+        self.entity()
+    }
+
+    pub fn update_batches(&mut self) {
+        for scene in std::iter::once(&mut self.scene).chain(self.scenes.iter_mut()) {
+            if !scene.component_pool_exists::<BatchMember>() {
+                continue;
+            }
+            let merged: BTreeMap<usize, MergedGeometry> = batch_members(scene)
+                .into_iter()
+                .filter_map(|(batch, members)| Some((batch, merge_geometry(&members)?)))
+                .collect();
+
+            let shapes = match scene.try_get_mut::<Box<dyn Renderable>>() {
+                Some(shapes) => shapes,
+                None => continue,
+            };
+            for (entity, shape) in shapes.iter_with_entities_unmarked_mut() {
+                if let Some(batch) = shape.batch_mut() {
+                    batch.upload(&mut self.renderer, merged.get(&entity));
+                }
+            }
+        }
+    }
+}
+
+pub type Member<'a> = (&'a VertexGroup, Option<&'a IndexData>);
+
+// Enabled members grouped by batch, each group in entity order so the merged geometry is stable.
+pub fn batch_members(scene: &Scene) -> BTreeMap<usize, Vec<Member<'_>>> {
+    let mut sorted: Vec<(usize, usize)> = match scene.try_get::<BatchMember>() {
+        Some(pool) => pool
+            .iter_with_entities()
+            .map(|(entity, BatchMember(batch))| (*batch, entity))
+            .collect(),
+        None => return BTreeMap::new(),
+    };
+    sorted.sort_unstable();
+
+    let mut members: BTreeMap<usize, Vec<Member>> = BTreeMap::new();
+    for (batch, entity) in sorted {
+        if let Some(vertex_group) = scene.get_component::<VertexGroup>(entity) {
+            members
+                .entry(batch)
+                .or_default()
+                .push((vertex_group, scene.get_component::<IndexData>(entity)));
+        }
+    }
+
+    members
+}
+
+pub struct MergedGeometry {
+    pub layout: VertexGroup,
+    pub vertices: Vec<u8>,
+    pub indices: Vec<u32>,
+}
+
+// Members are checked against the first one's layout. Shapes without indices are drawn as plain
+// triangle lists, so they get sequential indices.
+pub fn merge_geometry(members: &[Member]) -> Option<MergedGeometry> {
+    let (layout, _) = members.first()?;
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+    let mut base = 0;
+
+    for (vertex_group, member_indices) in members {
+        if !same_layout(vertex_group, layout) {
+            log::warn!("Skipping batched shape with a different vertex layout than its batch");
+            continue;
+        }
+
+        vertices.extend(vertex_group.get());
+        match member_indices {
+            Some(IndexData::U16(member)) => {
+                indices.extend(member.iter().map(|&index| base + index as u32))
+            }
+            Some(IndexData::U32(member)) => {
+                indices.extend(member.iter().map(|&index| base + index))
+            }
+            None => indices.extend(base..base + vertex_group.num_vertices),
+        }
+        base += vertex_group.num_vertices;
+    }
+
+    Some(MergedGeometry {
+        layout: layout.layout_only(),
+        vertices,
+        indices,
+    })
+}
+
+fn same_layout(left: &VertexGroup, right: &VertexGroup) -> bool {
+    left.stride == right.stride && left.layout == right.layout
+}
+
+// Rewrites the buffer in place, and only grows it when the merged geometry no longer fits. The outgrown
+// buffer may still be read by a frame in flight, so it goes through the release queue.
+fn stream(
+    renderer: &mut Renderer,
+    slot: &mut Option<(Buffer, BufferAddress)>,
+    usage: BufferUsages,
+    contents: &[u8],
+) {
+    let padded = padded(contents);
+    let size = padded.len() as BufferAddress;
+
+    if !matches!(slot, Some((_, capacity)) if *capacity >= size) {
+        let capacity = size.next_power_of_two().max(COPY_BUFFER_ALIGNMENT);
+        let buffer = renderer.device.create_buffer(&BufferDescriptor {
+            label: Some("Batch Buffer"),
+            size: capacity,
+            usage: usage | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        if let Some((old, _)) = slot.replace((buffer, capacity)) {
+            renderer.release(old);
+        }
+    }
+
+    if let Some((buffer, _)) = slot {
+        renderer.queue.write_buffer(buffer, 0, &padded);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wgpu::VertexFormat;
+
+    use crate::{
+        life::Scene,
+        manifestation::{
+            apex::{IndexData, VertexGroup},
+            silhouette::platter::{batch_members, merge_geometry, BatchMember},
+        },
+    };
+
+    fn triangle(offset: f32) -> VertexGroup {
+        let mut vertex_group = VertexGroup::new();
+        vertex_group.data(
+            [offset, 0.0, offset + 1.0, 0.0, offset, 1.0],
+            2,
+            VertexFormat::Float32x2,
+        );
+
+        vertex_group
+    }
+
+    fn quad() -> (VertexGroup, IndexData) {
+        let mut vertex_group = VertexGroup::new();
+        vertex_group.data(
+            [0.0_f32, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+            2,
+            VertexFormat::Float32x2,
+        );
+
+        (vertex_group, IndexData::U16(vec![0, 1, 2, 0, 2, 3]))
+    }
+
+    #[test]
+    fn merged_indices_are_offset_by_earlier_vertices() {
+        let first = triangle(0.0);
+        let (second, second_indices) = quad();
+        let third = triangle(5.0);
+
+        let merged = merge_geometry(&[
+            (&first, None),
+            (&second, Some(&second_indices)),
+            (&third, None),
+        ])
+        .unwrap();
+
+        assert_eq!(merged.vertices.len(), (3 + 4 + 3) * 2 * 4);
+        assert_eq!(merged.indices, vec![0, 1, 2, 3, 4, 5, 3, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn merged_vertices_keep_member_order() {
+        let first = triangle(0.0);
+        let second = triangle(5.0);
+
+        let merged = merge_geometry(&[(&second, None), (&first, None)]).unwrap();
+
+        assert_eq!(merged.vertices, [second.get(), first.get()].concat());
+    }
+
+    #[test]
+    fn members_with_another_layout_are_skipped() {
+        let first = triangle(0.0);
+        let mut colored = triangle(1.0);
+        colored.data([1.0_f32; 9], 3, VertexFormat::Float32x3);
+
+        let merged = merge_geometry(&[(&first, None), (&colored, None)]).unwrap();
+
+        assert_eq!(merged.vertices, first.get());
+        assert_eq!(merged.indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn batches_without_members_have_no_geometry() {
+        assert!(merge_geometry(&[]).is_none());
+    }
+
+    #[test]
+    fn hidden_members_are_left_out_of_their_batch() {
+        let mut scene = Scene::new();
+        let batch = scene.entity();
+        let other_batch = scene.entity();
+        let mut members = Vec::new();
+        for (offset, owner) in [(0.0, batch), (1.0, other_batch), (2.0, batch), (3.0, batch)] {
+            let entity = scene.entity();
+            scene.component(entity, triangle(offset));
+            scene.component(entity, BatchMember(owner.raw_id()));
+            members.push(entity);
+        }
+        scene.disable::<BatchMember>(members[2]);
+
+        let grouped = batch_members(&scene);
+
+        assert_eq!(grouped[&batch.raw_id()].len(), 2);
+        assert_eq!(grouped[&batch.raw_id()][0].0.get(), triangle(0.0).get());
+        assert_eq!(grouped[&batch.raw_id()][1].0.get(), triangle(3.0).get());
+        assert_eq!(grouped[&other_batch.raw_id()].len(), 1);
+    }
+}
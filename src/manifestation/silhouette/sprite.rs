@@ -0,0 +1,411 @@
+use std::path::Path;
+
+use bytemuck::{Pod, Zeroable};
+use image::RgbaImage;
+use wgpu::{
+    include_wgsl,
+    util::{BufferInitDescriptor, DeviceExt},
+    AddressMode, BindGroup, BindGroupDescriptor, BindGroupEntry, BindGroupLayout,
+    BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingResource, BindingType, BlendState,
+    Buffer, BufferAddress, BufferUsages, ColorTargetState, ColorWrites, CompareFunction,
+    DepthBiasState, DepthStencilState, Device, Extent3d, Features, FilterMode, FragmentState,
+    ImageCopyTexture, ImageDataLayout, IndexFormat, MultisampleState, Origin3d,
+    PipelineLayoutDescriptor, PrimitiveState, PushConstantRange, RenderPass, RenderPipeline,
+    RenderPipelineDescriptor, SamplerBindingType, SamplerDescriptor, ShaderStages, StencilState,
+    Texture, TextureAspect, TextureDescriptor, TextureDimension, TextureFormat, TextureSampleType,
+    TextureUsages, TextureViewDescriptor, TextureViewDimension, VertexAttribute,
+    VertexBufferLayout, VertexFormat, VertexState, VertexStepMode,
+};
+
+use crate::{
+    calamity::IgnitionError,
+    life::Entity,
+    manifestation::{
+        easel::RenderContext, nostalgia::DEPTH_FORMAT, posture::ModelBinding,
+        silhouette::Renderable, Renderer,
+    },
+    Engine,
+};
+
+pub const QUAD_INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
+pub const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+// Lower left corner and size of the quad on the z = 0 plane, before the entity's Transform.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SpriteVertex {
+    pub position: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+unsafe impl Zeroable for SpriteVertex {}
+unsafe impl Pod for SpriteVertex {}
+
+impl SpriteVertex {
+    const ATTRIBUTES: [VertexAttribute; 2] = [
+        VertexAttribute {
+            offset: 0,
+            shader_location: 0,
+            format: VertexFormat::Float32x3,
+        },
+        VertexAttribute {
+            offset: std::mem::size_of::<[f32; 3]>() as BufferAddress,
+            shader_location: 1,
+            format: VertexFormat::Float32x2,
+        },
+    ];
+
+    pub fn layout<'a>() -> VertexBufferLayout<'a> {
+        VertexBufferLayout {
+            array_stride: std::mem::size_of::<SpriteVertex>() as BufferAddress,
+            step_mode: VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+// Counter-clockwise from the lower left corner. Image rows go downwards, so v is flipped.
+pub fn quad_vertices(rect: Rect) -> [SpriteVertex; 4] {
+    let Rect {
+        x,
+        y,
+        width,
+        height,
+    } = rect;
+
+    [
+        SpriteVertex {
+            position: [x, y, 0.0],
+            uv: [0.0, 1.0],
+        },
+        SpriteVertex {
+            position: [x + width, y, 0.0],
+            uv: [1.0, 1.0],
+        },
+        SpriteVertex {
+            position: [x + width, y + height, 0.0],
+            uv: [1.0, 0.0],
+        },
+        SpriteVertex {
+            position: [x, y + height, 0.0],
+            uv: [0.0, 0.0],
+        },
+    ]
+}
+
+#[derive(Debug)]
+pub struct Sprite {
+    pub pipeline: RenderPipeline,
+    pub vertex_buffer: Buffer,
+    pub index_buffer: Buffer,
+    pub texture: Texture,
+    pub bind_group: BindGroup,
+    pub model: ModelBinding,
+
+    pub tint: [f32; 4],
+}
+
+impl Renderer {
+    pub fn sprite(
+        &self,
+        path: &Path,
+        rect: Rect,
+        tint: Option<[f32; 4]>,
+    ) -> Result<Box<dyn Renderable>, IgnitionError> {
+        let image = image::open(path)
+            .map_err(|error| IgnitionError::Image {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            })?
+            .to_rgba8();
+
+        self.sprite_from_image(&image, rect, tint)
+    }
+
+    pub fn sprite_from_image(
+        &self,
+        image: &RgbaImage,
+        rect: Rect,
+        tint: Option<[f32; 4]>,
+    ) -> Result<Box<dyn Renderable>, IgnitionError> {
+        if !self.device.features().contains(Features::PUSH_CONSTANTS) {
+            return Err(IgnitionError::Unsupported(
+                "sprites need push constants, which this adapter does not support",
+            ));
+        }
+
+        let texture = self.sprite_texture(image);
+        let layout = sprite_layout(&self.device);
+        let bind_group = self.sprite_bind_group(&texture, &layout);
+
+        let sprite = Sprite {
+            pipeline: self.sprite_pipeline(&layout),
+            vertex_buffer: self.device.create_buffer_init(&BufferInitDescriptor {
+                label: Some("Sprite Vertex Buffer"),
+                contents: bytemuck::cast_slice(&quad_vertices(rect)),
+                usage: BufferUsages::VERTEX,
+            }),
+            index_buffer: self.device.create_buffer_init(&BufferInitDescriptor {
+                label: Some("Sprite Index Buffer"),
+                contents: bytemuck::cast_slice(&QUAD_INDICES),
+                usage: BufferUsages::INDEX,
+            }),
+            texture,
+            bind_group,
+            model: ModelBinding::new(&self.device, &self.model_layout),
+
+            tint: tint.unwrap_or(WHITE),
+        };
+
+        Ok(self.track(sprite))
+    }
+
+    fn sprite_texture(&self, image: &RgbaImage) -> Texture {
+        let (width, height) = image.dimensions();
+        let size = Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+
+        let texture = self.device.create_texture(&TextureDescriptor {
+            label: Some("Sprite Texture"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Rgba8UnormSrgb,
+            usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+        });
+
+        self.queue.write_texture(
+            ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: Origin3d::ZERO,
+                aspect: TextureAspect::All,
+            },
+            image.as_raw(),
+            ImageDataLayout {
+                offset: 0,
+                bytes_per_row: std::num::NonZeroU32::new(4 * width),
+                rows_per_image: std::num::NonZeroU32::new(height),
+            },
+            size,
+        );
+
+        texture
+    }
+
+    fn sprite_bind_group(&self, texture: &Texture, layout: &BindGroupLayout) -> BindGroup {
+        let view = texture.create_view(&TextureViewDescriptor::default());
+        let sampler = self.device.create_sampler(&SamplerDescriptor {
+            label: Some("Sprite Sampler"),
+            address_mode_u: AddressMode::ClampToEdge,
+            address_mode_v: AddressMode::ClampToEdge,
+            address_mode_w: AddressMode::ClampToEdge,
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            mipmap_filter: FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        self.device.create_bind_group(&BindGroupDescriptor {
+            label: Some("Sprite Bind Group"),
+            layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(&view),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(&sampler),
+                },
+            ],
+        })
+    }
+
+    fn sprite_pipeline(&self, layout: &BindGroupLayout) -> RenderPipeline {
+        let shader = self
+            .device
+            .create_shader_module(include_wgsl!("../shaders/sprite.wgsl"));
+
+        let pipeline_layout = self
+            .device
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some("Sprite Pipeline Layout"),
+                bind_group_layouts: &[&self.camera.layout, &self.model_layout, layout],
+                push_constant_ranges: &[PushConstantRange {
+                    stages: ShaderStages::FRAGMENT,
+                    range: 0..std::mem::size_of::<[f32; 4]>() as u32,
+                }],
+            });
+
+        self.device
+            .create_render_pipeline(&RenderPipelineDescriptor {
+                label: Some("Sprite Pipeline"),
+                layout: Some(&pipeline_layout),
+                vertex: VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[SpriteVertex::layout()],
+                },
+                fragment: Some(FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(ColorTargetState {
+                        format: self.config.format,
+                        blend: Some(BlendState::ALPHA_BLENDING),
+                        write_mask: ColorWrites::ALL,
+                    })],
+                }),
+                primitive: PrimitiveState::default(),
+                depth_stencil: Some(DepthStencilState {
+                    format: DEPTH_FORMAT,
+                    depth_write_enabled: true,
+                    depth_compare: CompareFunction::LessEqual,
+                    stencil: StencilState::default(),
+                    bias: DepthBiasState::default(),
+                }),
+                multisample: MultisampleState {
+                    count: self.sample_count,
+                    ..Default::default()
+                },
+                multiview: None,
+            })
+    }
+}
+
+pub fn sprite_layout(device: &Device) -> BindGroupLayout {
+    device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+        label: Some("Sprite Bind Group Layout"),
+        entries: &[
+            BindGroupLayoutEntry {
+                binding: 0,
+                visibility: ShaderStages::FRAGMENT,
+                ty: BindingType::Texture {
+                    sample_type: TextureSampleType::Float { filterable: true },
+                    view_dimension: TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            },
+            BindGroupLayoutEntry {
+                binding: 1,
+                visibility: ShaderStages::FRAGMENT,
+                ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                count: None,
+            },
+        ],
+    })
+}
+
+impl Renderable for Sprite {
+    fn render<'a>(&'a self, render_pass: &mut RenderPass<'a>, _context: &RenderContext<'a>) {
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(1, &self.model.bind_group, &[]);
+        render_pass.set_bind_group(2, &self.bind_group, &[]);
+        render_pass.set_push_constants(ShaderStages::FRAGMENT, 0, bytemuck::cast_slice(&self.tint));
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.set_index_buffer(self.index_buffer.slice(..), IndexFormat::Uint16);
+        render_pass.draw_indexed(0..QUAD_INDICES.len() as u32, 0, 0..1);
+    }
+
+    fn model(&self) -> Option<&ModelBinding> {
+        Some(&self.model)
+    }
+}
+
+impl Engine {
+    pub fn sprite(
+        &mut self,
+        path: &Path,
+        rect: Rect,
+        tint: Option<[f32; 4]>,
+    ) -> Result<Entity, IgnitionError> {
+        let entity = self.building();
+        let sprite = self.renderer.sprite(path, rect, tint)?;
+        self.scene.component(entity, sprite);
+
+        Ok(self.entity())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naga::{
+        front::wgsl,
+        valid::{Capabilities, ValidationFlags, Validator},
+    };
+
+    use crate::manifestation::silhouette::sprite::{quad_vertices, Rect, QUAD_INDICES};
+
+    #[test]
+    fn quads_cover_the_rect() {
+        let positions: Vec<[f32; 3]> = quad_vertices(Rect::new(1.0, 2.0, 3.0, 4.0))
+            .iter()
+            .map(|vertex| vertex.position)
+            .collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                [1.0, 2.0, 0.0],
+                [4.0, 2.0, 0.0],
+                [4.0, 6.0, 0.0],
+                [1.0, 6.0, 0.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn the_top_of_the_image_maps_to_the_top_of_the_quad() {
+        let vertices = quad_vertices(Rect::new(0.0, 0.0, 1.0, 1.0));
+
+        assert_eq!(vertices[0].uv, [0.0, 1.0]);
+        assert_eq!(vertices[2].uv, [1.0, 0.0]);
+    }
+
+    #[test]
+    fn quad_triangles_wind_counter_clockwise() {
+        let vertices = quad_vertices(Rect::new(0.0, 0.0, 2.0, 1.0));
+
+        for triangle in QUAD_INDICES.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| vertices[triangle[i] as usize].position);
+            let area = (b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1]);
+
+            assert!(area > 0.0);
+        }
+    }
+
+    #[test]
+    fn sprite_shader_validates_with_push_constants() {
+        let module = wgsl::parse_str(include_str!("../shaders/sprite.wgsl")).unwrap();
+
+        assert!(
+            Validator::new(ValidationFlags::all(), Capabilities::PUSH_CONSTANT)
+                .validate(&module)
+                .is_ok()
+        );
+    }
+}
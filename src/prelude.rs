@@ -1,3 +1,15 @@
 pub use wgpu::include_wgsl;
+pub use winit::event::{MouseButton, VirtualKeyCode};
 
-pub use crate::{life::Scene, Engine};
+pub use crate::{
+    alliance::Plugin,
+    life::{Entity, Scene},
+    manifestation::artist::LoopControl,
+    manifestation::easel::{CustomDraw, RenderContext},
+    manifestation::lattice::GridPlugin,
+    manifestation::metamorphosis::ShaderSource,
+    manifestation::posture::Transform,
+    manifestation::silhouette::platter::BatchMember,
+    manifestation::silhouette::sprite::Rect,
+    Engine,
+};
@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+
+use winit::{
+    dpi::{PhysicalPosition, PhysicalSize},
+    event::{ElementState, MouseButton, VirtualKeyCode},
+};
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct InputState {
+    pressed_keys: HashSet<VirtualKeyCode>,
+    just_pressed_keys: HashSet<VirtualKeyCode>,
+
+    mouse_position: (f64, f64),
+    pressed_mouse_buttons: HashSet<MouseButton>,
+}
+
+impl InputState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_pressed(&self, key: VirtualKeyCode) -> bool {
+        self.pressed_keys.contains(&key)
+    }
+
+    pub fn just_pressed(&self, key: VirtualKeyCode) -> bool {
+        self.just_pressed_keys.contains(&key)
+    }
+
+    pub fn keyboard_input(&mut self, key: VirtualKeyCode, state: ElementState) {
+        match state {
+            ElementState::Pressed => {
+                if self.pressed_keys.insert(key) {
+                    self.just_pressed_keys.insert(key);
+                }
+            }
+            ElementState::Released => {
+                self.pressed_keys.remove(&key);
+            }
+        }
+    }
+
+    pub fn mouse_position(&self) -> (f64, f64) {
+        self.mouse_position
+    }
+
+    pub fn mouse_button_pressed(&self, button: MouseButton) -> bool {
+        self.pressed_mouse_buttons.contains(&button)
+    }
+
+    pub fn cursor_moved(&mut self, position: PhysicalPosition<f64>, size: PhysicalSize<u32>) {
+        if size.width > 0 && size.height > 0 {
+            self.mouse_position = (
+                position.x / size.width as f64 * 2.0 - 1.0,
+                1.0 - position.y / size.height as f64 * 2.0,
+            );
+        }
+    }
+
+    pub fn mouse_input(&mut self, button: MouseButton, state: ElementState) {
+        match state {
+            ElementState::Pressed => {
+                self.pressed_mouse_buttons.insert(button);
+            }
+            ElementState::Released => {
+                self.pressed_mouse_buttons.remove(&button);
+            }
+        }
+    }
+
+    pub fn refresh(&mut self) {
+        self.just_pressed_keys.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use winit::{
+        dpi::{PhysicalPosition, PhysicalSize},
+        event::{ElementState, MouseButton, VirtualKeyCode},
+    };
+
+    use crate::senses::InputState;
+
+    #[test]
+    fn pressing_a_key_marks_it_as_pressed_and_just_pressed() {
+        let mut input = InputState::new();
+
+        input.keyboard_input(VirtualKeyCode::W, ElementState::Pressed);
+
+        assert!(input.is_pressed(VirtualKeyCode::W));
+        assert!(input.just_pressed(VirtualKeyCode::W));
+        assert!(!input.is_pressed(VirtualKeyCode::S));
+    }
+
+    #[test]
+    fn refreshing_input_only_resets_just_pressed_keys() {
+        let mut input = InputState::new();
+
+        input.keyboard_input(VirtualKeyCode::W, ElementState::Pressed);
+        input.refresh();
+
+        assert!(input.is_pressed(VirtualKeyCode::W));
+        assert!(!input.just_pressed(VirtualKeyCode::W));
+    }
+
+    #[test]
+    fn holding_a_key_doesn_t_repeat_just_pressed() {
+        let mut input = InputState::new();
+
+        input.keyboard_input(VirtualKeyCode::Escape, ElementState::Pressed);
+        input.refresh();
+        input.keyboard_input(VirtualKeyCode::Escape, ElementState::Pressed);
+
+        assert!(!input.just_pressed(VirtualKeyCode::Escape));
+    }
+
+    #[test]
+    fn releasing_a_key_clears_it() {
+        let mut input = InputState::new();
+
+        input.keyboard_input(VirtualKeyCode::W, ElementState::Pressed);
+        input.keyboard_input(VirtualKeyCode::W, ElementState::Released);
+
+        assert!(!input.is_pressed(VirtualKeyCode::W));
+    }
+
+    #[test]
+    fn moving_the_cursor_stores_normalized_device_coordinates() {
+        let mut input = InputState::new();
+        let size = PhysicalSize::new(800, 600);
+
+        input.cursor_moved(PhysicalPosition::new(400.0, 300.0), size);
+        assert_eq!(input.mouse_position(), (0.0, 0.0));
+
+        input.cursor_moved(PhysicalPosition::new(0.0, 0.0), size);
+        assert_eq!(input.mouse_position(), (-1.0, 1.0));
+
+        input.cursor_moved(PhysicalPosition::new(800.0, 600.0), size);
+        assert_eq!(input.mouse_position(), (1.0, -1.0));
+    }
+
+    #[test]
+    fn moving_the_cursor_in_a_minimized_window_keeps_last_position() {
+        let mut input = InputState::new();
+
+        input.cursor_moved(PhysicalPosition::new(0.0, 0.0), PhysicalSize::new(800, 600));
+        input.cursor_moved(PhysicalPosition::new(10.0, 10.0), PhysicalSize::new(0, 0));
+
+        assert_eq!(input.mouse_position(), (-1.0, 1.0));
+    }
+
+    #[test]
+    fn pressing_and_releasing_mouse_buttons_updates_state() {
+        let mut input = InputState::new();
+
+        input.mouse_input(MouseButton::Left, ElementState::Pressed);
+        assert!(input.mouse_button_pressed(MouseButton::Left));
+        assert!(!input.mouse_button_pressed(MouseButton::Right));
+
+        input.mouse_input(MouseButton::Left, ElementState::Released);
+        assert!(!input.mouse_button_pressed(MouseButton::Left));
+    }
+}
@@ -0,0 +1,146 @@
+use std::{mem, time::Duration};
+
+use crate::{life::Scene, Engine};
+
+impl Engine {
+    pub fn push_scene(&mut self, scene: Scene) {
+        let below = mem::replace(&mut self.scene, scene);
+        self.scenes.push(below);
+        self.building = None;
+    }
+
+    pub fn pop_scene(&mut self) -> Option<Scene> {
+        let below = self.scenes.pop()?;
+        self.building = None;
+
+        Some(mem::replace(&mut self.scene, below))
+    }
+
+    pub fn replace_scene(&mut self, scene: Scene) -> Scene {
+        self.building = None;
+
+        mem::replace(&mut self.scene, scene)
+    }
+
+    pub fn scene_depth(&self) -> usize {
+        self.scenes.len() + 1
+    }
+
+    pub fn run_scene_systems(&mut self, delta: Duration) {
+        let updated = scenes_below(&self.scene, &self.scenes, |scene| scene.update_below);
+        let first_updated = self.scenes.len() - updated;
+
+        for scene in self.scenes[first_updated..].iter_mut() {
+            scene.advance_time(delta);
+            scene.tick_timers();
+            scene.advance_deferred_spawns();
+            scene.run_systems();
+            scene.apply_commands();
+            scene.clear_events();
+        }
+        self.scene.advance_time(delta);
+        self.scene.tick_timers();
+        self.scene.advance_deferred_spawns();
+        self.scene.run_systems();
+    }
+
+    pub fn rendered_scenes_mut(&mut self) -> impl Iterator<Item = &mut Scene> {
+        let rendered = scenes_below(&self.scene, &self.scenes, |scene| scene.render_below);
+        let first_rendered = self.scenes.len() - rendered;
+
+        self.scenes[first_rendered..]
+            .iter_mut()
+            .chain(std::iter::once(&mut self.scene))
+    }
+
+    pub fn rendered_scenes(&self) -> impl Iterator<Item = &Scene> {
+        let rendered = scenes_below(&self.scene, &self.scenes, |scene| scene.render_below);
+        let first_rendered = self.scenes.len() - rendered;
+
+        self.scenes[first_rendered..]
+            .iter()
+            .chain(std::iter::once(&self.scene))
+    }
+}
+
+pub fn scenes_below<F>(top: &Scene, below: &[Scene], see_through: F) -> usize
+where
+    F: Fn(&Scene) -> bool,
+{
+    let mut current = top;
+    let mut count = 0;
+
+    for scene in below.iter().rev() {
+        if !see_through(current) {
+            break;
+        }
+
+        current = scene;
+        count += 1;
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{life::Scene, theatre::scenes_below};
+
+    fn scene(render_below: bool, update_below: bool) -> Scene {
+        let mut scene = Scene::new();
+        scene.render_below = render_below;
+        scene.update_below = update_below;
+
+        scene
+    }
+
+    #[test]
+    fn opaque_top_scene_hides_every_scene_below() {
+        let below = vec![scene(true, true), scene(true, true)];
+
+        assert_eq!(
+            scenes_below(&scene(false, false), &below, |s| s.render_below),
+            0
+        );
+        assert_eq!(
+            scenes_below(&scene(false, false), &below, |s| s.update_below),
+            0
+        );
+    }
+
+    #[test]
+    fn see_through_flags_chain_down_until_an_opaque_scene() {
+        let below = vec![scene(false, false), scene(false, true), scene(true, false)];
+        let top = scene(true, true);
+
+        assert_eq!(scenes_below(&top, &below, |s| s.render_below), 2);
+        assert_eq!(scenes_below(&top, &below, |s| s.update_below), 1);
+    }
+
+    #[test]
+    fn render_below_and_update_below_are_independent() {
+        let below = vec![scene(false, false)];
+
+        assert_eq!(
+            scenes_below(&scene(true, false), &below, |s| s.render_below),
+            1
+        );
+        assert_eq!(
+            scenes_below(&scene(true, false), &below, |s| s.update_below),
+            0
+        );
+        assert_eq!(
+            scenes_below(&scene(false, true), &below, |s| s.render_below),
+            0
+        );
+        assert_eq!(
+            scenes_below(&scene(false, true), &below, |s| s.update_below),
+            1
+        );
+    }
+
+    #[test]
+    fn a_lone_scene_has_nothing_below() {
+        assert_eq!(scenes_below(&scene(true, true), &[], |s| s.render_below), 0);
+    }
+}
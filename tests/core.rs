@@ -6,7 +6,7 @@ use ignition::{manifestation::silhouette::Renderable, prelude::*};
 #[ignore]
 #[test]
 fn alternating_triangles() {
-    let mut engine = Engine::ignite();
+    let mut engine = Engine::ignite_default();
 
     let triangle1 = engine
         .xy([0.55, -0.5, 0.55, 0.55, -0.5, 0.55])
@@ -31,46 +31,35 @@ fn alternating_triangles() {
             engine.scene.toggle::<Box<dyn Renderable>>(triangle1);
             engine.scene.toggle::<Box<dyn Renderable>>(triangle2);
         }
+
+        LoopControl::Continue
     });
 }
 
-/*
-const POLYGON_VERTICES: &[Vertex] = &[
-    Vertex {
-        position: [-0.0868241, 0.49240386, 0.0],
-        color: [0.5, 0.0, 0.5],
-    },
-    Vertex {
-        position: [-0.49513406, 0.06958647, 0.0],
-        color: [0.5, 0.0, 0.5],
-    },
-    Vertex {
-        position: [-0.21918549, -0.44939706, 0.0],
-        color: [0.5, 0.0, 0.5],
-    },
-    Vertex {
-        position: [0.35966998, -0.3473291, 0.0],
-        color: [0.5, 0.0, 0.5],
-    },
-    Vertex {
-        position: [0.44147372, 0.2347359, 0.0],
-        color: [0.5, 0.0, 0.5],
-    },
+#[rustfmt::skip]
+const POLYGON_VERTICES: [f32; 15] = [
+    -0.0868241, 0.49240386, 0.0,
+    -0.49513406, 0.06958647, 0.0,
+    -0.21918549, -0.44939706, 0.0,
+    0.35966998, -0.3473291, 0.0,
+    0.44147372, 0.2347359, 0.0,
+];
+const POLYGON_COLORS: [f32; 15] = [
+    0.5, 0.0, 0.5, 0.5, 0.0, 0.5, 0.5, 0.0, 0.5, 0.5, 0.0, 0.5, 0.5, 0.0, 0.5,
 ];
-const POLYGON_INDICES: &[u16] = &[0, 1, 4, 1, 2, 4, 2, 3, 4];
+const POLYGON_INDICES: [u16; 9] = [0, 1, 4, 1, 2, 4, 2, 3, 4];
+
 #[ignore]
 #[test]
 fn polygon() {
-    let options = OptionsBuilder::default().any_thread(true).build();
-    let mut engine = Engine::ignite(options);
-    let polygon = indexed_shape(
-        &mut engine,
-        &Vec::from(POLYGON_VERTICES),
-        &Vec::from(POLYGON_INDICES),
-        include_wgsl!("shaders/gradient.wgsl"),
-    );
-    game_loop! (
-        draw!(polygon);
-    );
+    let mut engine = Engine::ignite_default();
+
+    engine
+        .xyz(POLYGON_VERTICES)
+        .rgb(POLYGON_COLORS)
+        .indices(POLYGON_INDICES.to_vec())
+        .component(include_wgsl!("shaders/gradient.wgsl"))
+        .doritos();
+
+    engine.game_loop(|_engine: &mut Engine| LoopControl::Continue);
 }
-*/